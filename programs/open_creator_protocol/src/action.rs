@@ -1,4 +1,5 @@
-use crate::state::MintState;
+use crate::merkle::{MerkleProof, MerkleProofMemo};
+use crate::state::{MintState, Policy};
 use anchor_lang::prelude::*;
 use anchor_spl::{
     metadata::MetadataAccount,
@@ -6,7 +7,10 @@ use anchor_spl::{
 };
 use serde::{Deserialize, Serialize};
 use solana_program::{
-    instruction::Instruction, program_option::COption, serialize_utils::read_u16, sysvar::instructions::load_instruction_at_checked,
+    instruction::Instruction,
+    program_option::COption,
+    serialize_utils::read_u16,
+    sysvar::instructions::{get_instruction_relative, load_instruction_at_checked},
 };
 use std::cmp::max;
 
@@ -18,11 +22,14 @@ pub struct ActionCtx {
     pub mint_state: MintStateCtx,
     pub mint_account: Option<MintAccountCtx>,
     pub metadata: Option<MetadataCtx>,
+    pub policy: Option<PolicyCtx>,
     pub payer: Option<String>,
     pub from: Option<String>, // owner of the from_account, and many action's initiator
     pub to: Option<String>,   // owner of the to_account
     pub last_memo_signer: Option<String>,
     pub last_memo_data: Option<String>,
+    #[serde(skip)]
+    pub merkle_proofs: Vec<MerkleProof>, // from the instruction data or the memo, for the merkle operators
 }
 
 impl ActionCtx {
@@ -41,6 +48,9 @@ impl ActionCtx {
             Ok(s) => Some(s),
             Err(_) => None,
         };
+        if let Some(memo_data) = &self.last_memo_data {
+            self.merkle_proofs.extend(MerkleProofMemo::parse(memo_data));
+        }
     }
 
     pub fn parse_instructions(&mut self, ixs: &AccountInfo<'_>) -> Result<()> {
@@ -59,6 +69,15 @@ impl ActionCtx {
     }
 }
 
+// the program of the top level instruction that is currently executing, None when ocp is called directly
+pub fn get_calling_program(ixs: &AccountInfo<'_>) -> Result<Option<Pubkey>> {
+    let ix = get_instruction_relative(0, ixs)?;
+    if ix.program_id == crate::id() {
+        return Ok(None);
+    }
+    Ok(Some(ix.program_id))
+}
+
 fn to_option_str(c_option: COption<Pubkey>) -> Option<String> {
     match c_option {
         COption::Some(pubkey) => Some(pubkey.to_string()),
@@ -66,6 +85,21 @@ fn to_option_str(c_option: COption<Pubkey>) -> Option<String> {
     }
 }
 
+#[derive(Default, Serialize, Deserialize)]
+pub struct PolicyCtx {
+    pub frozen: bool,
+    pub authority_frozen: bool,
+}
+
+impl From<&Policy> for PolicyCtx {
+    fn from(policy: &Policy) -> Self {
+        PolicyCtx {
+            frozen: policy.frozen,
+            authority_frozen: policy.authority_frozen,
+        }
+    }
+}
+
 #[derive(Default, Serialize, Deserialize)]
 pub struct MetadataCtx {
     pub name: String,
@@ -77,6 +111,12 @@ pub struct MetadataCtx {
 
 impl From<Box<Account<'_, MetadataAccount>>> for MetadataCtx {
     fn from(metadata: Box<Account<'_, MetadataAccount>>) -> Self {
+        MetadataCtx::from(&**metadata)
+    }
+}
+
+impl From<&MetadataAccount> for MetadataCtx {
+    fn from(metadata: &MetadataAccount) -> Self {
         Self {
             name: metadata.name.clone(),
             symbol: metadata.symbol.clone(),
@@ -117,6 +157,12 @@ pub struct MintAccountCtx {
 
 impl From<Box<Account<'_, Mint>>> for MintAccountCtx {
     fn from(mint: Box<Account<'_, Mint>>) -> Self {
+        MintAccountCtx::from(&**mint)
+    }
+}
+
+impl From<&Mint> for MintAccountCtx {
+    fn from(mint: &Mint) -> Self {
         MintAccountCtx {
             mint_authority: to_option_str(mint.mint_authority),
             supply: mint.supply,
@@ -162,7 +208,13 @@ impl From<MintState> for MintStateCtx {
             Ok(clock) => clock.unix_timestamp,
             Err(_) => 0, // use 0 as the default when Clock is not available, usually in test
         };
+        MintStateCtx::new(mint_state, now)
+    }
+}
 
+impl MintStateCtx {
+    // now is the unix timestamp of the cluster clock, passed in by the off-chain clients
+    pub fn new(mint_state: MintState, now: i64) -> Self {
         MintStateCtx {
             version: mint_state.version,
             policy: mint_state.policy.to_string(),
@@ -180,7 +232,12 @@ impl From<MintState> for MintStateCtx {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::state::Policy;
+    use crate::client::{self, ClientAccount, ClientAction};
+    use crate::merkle::MerkleTree;
+    use crate::royalty::RoyaltyRecipient;
+    use crate::rule::{trace_json_rule, CompiledRule, RuleEvent, TRACE_STATUS_MET, TRACE_STATUS_NOT_MET, TRACE_STATUS_UNKNOWN};
+    use crate::state::{ActionRule, ListAccount, MintCounter, Policy, PolicyUpdate, ProgramConfig, RoyaltyExemption, RuleChunk, RuleCounter};
+    use json_rules_engine_fork::Status;
 
     fn policy_fixture() -> Policy {
         Policy {
@@ -190,7 +247,43 @@ mod tests {
             authority: Pubkey::new_unique(),
             dynamic_royalty: None,
             json_rule: Some(r#"{"conditions":{"and":[{"field":"action","operator":"string_not_equals","value":""}]},"events":[]}"#.to_string()),
+            royalty_split: None,
+            royalty_enforcement_mode: 0,
+            compiled_rule: None,
+            rule_chunk_count: 0,
+            action_rules: vec![],
+            update_delay: 0,
+            pending_authority: None,
+            rule_admin: None,
+            royalty_admin: None,
+            pause_guardian: None,
+            unmanaged: false,
+            frozen: false,
+            authority_frozen: false,
+            account_count: 0,
+            paused: false,
+        }
+    }
+
+    // evaluates the policy with both the json rules engine and the compiled rule, they must agree
+    fn matches(policy: &Policy, action_ctx: &ActionCtx) -> Result<()> {
+        let mut json_policy = policy.clone();
+        json_policy.compiled_rule = None;
+        json_policy.action_rules.iter_mut().for_each(|x| x.compiled_rule = None);
+        let json_result = json_policy.matches(action_ctx, &[]);
+
+        let mut compiled_policy = policy.clone();
+        compiled_policy.compile_rule();
+        if compiled_policy.json_rule.as_ref().is_some_and(|x| !x.is_empty()) {
+            assert!(compiled_policy.compiled_rule.is_some(), "json_rule should be compiled");
         }
+        if let Some(compiled_rule) = &compiled_policy.compiled_rule {
+            assert_eq!(compiled_rule.trace(action_ctx, &[]).0, compiled_rule.evaluate(action_ctx, &[]));
+        }
+        let compiled_result = compiled_policy.matches(action_ctx, &[]);
+
+        assert_eq!(json_result.is_ok(), compiled_result.is_ok());
+        compiled_result
     }
 
     fn action_ctx_fixture() -> ActionCtx {
@@ -199,10 +292,12 @@ mod tests {
             program_ids: vec![],
             last_memo_data: None,
             last_memo_signer: None,
+            merkle_proofs: vec![],
             mint: Pubkey::new_unique().to_string(),
             mint_state: MintState::default().into(),
             mint_account: None,
             metadata: None,
+            policy: None,
             payer: Some(Pubkey::new_unique().to_string()),
             from: Some(Pubkey::new_unique().to_string()),
             to: Some(Pubkey::new_unique().to_string()),
@@ -266,6 +361,672 @@ mod tests {
             ),
         );
         assert!(policy.valid().is_err());
+
+        // the timelock, and the updates are validated like the new policies
+        let mut policy = policy_fixture();
+        policy.update_delay = Policy::UPDATE_DELAY_MAX;
+        assert!(policy.valid().is_ok());
+        policy.update_delay = -1;
+        assert!(policy.valid().is_err());
+        let update = PolicyUpdate {
+            update_delay: Policy::UPDATE_DELAY_MAX + 1,
+            ..Default::default()
+        };
+        assert!(policy.update(update).is_err());
+        let update = PolicyUpdate {
+            json_rule: policy.json_rule.clone(),
+            update_delay: 86400,
+            ..Default::default()
+        };
+        assert!(policy.update(update).is_ok());
+        assert_eq!(policy.update_delay, 86400);
+    }
+
+    #[test]
+    fn test_policy_roles() {
+        let mut policy = policy_fixture();
+        let authority = policy.authority;
+        let managed = Pubkey::new_unique();
+        let program_config = ProgramConfig {
+            managed_authorities: vec![managed],
+            ..Default::default()
+        };
+        let config = Some(&program_config);
+        let rule_admin = Pubkey::new_unique();
+        assert!(policy.is_rule_admin(&authority, None) && policy.is_royalty_admin(&authority, None) && policy.is_pause_guardian(&authority, None));
+        assert!(!policy.is_rule_admin(&rule_admin, config));
+
+        policy.rule_admin = Some(rule_admin);
+        assert!(policy.is_rule_admin(&rule_admin, None) && policy.is_rule_admin(&managed, config));
+        assert!(!policy.is_rule_admin(&authority, config));
+        assert!(policy.is_royalty_admin(&authority, None) && !policy.is_royalty_admin(&rule_admin, None));
+
+        // the managed authorities need the program config, and the policy can opt out
+        assert!(!policy.is_authority(&managed, None) && policy.is_authority(&managed, config));
+        policy.unmanaged = true;
+        assert!(!policy.is_authority(&managed, config) && !policy.is_rule_admin(&managed, config));
+        policy.authority = managed;
+        assert!(!policy.is_managed(config));
+        policy.unmanaged = false;
+        assert!(policy.is_managed(config));
+
+        // the rule admin can't change the royalties through update_policy
+        let update = PolicyUpdate {
+            json_rule: policy.json_rule.clone(),
+            ..Default::default()
+        };
+        assert!(!policy.is_royalty_update(&update).unwrap());
+        let update = PolicyUpdate {
+            royalty_enforcement_mode: 1,
+            ..Default::default()
+        };
+        assert!(policy.is_royalty_update(&update).unwrap());
+
+        // each admin can only change its own fields
+        let royalty_admin = Pubkey::new_unique();
+        policy.royalty_admin = Some(royalty_admin);
+        assert!(policy.is_update_admin(&rule_admin, None) && policy.is_update_admin(&royalty_admin, None));
+        assert!(!policy.is_update_admin(&authority, None));
+        let update = PolicyUpdate {
+            json_rule: policy.json_rule.clone(),
+            royalty_enforcement_mode: 1,
+            ..Default::default()
+        };
+        assert!(!policy.is_rule_update(&update));
+        assert!(policy.check_update_authority(&royalty_admin, &update, None).is_ok());
+        assert!(policy.check_update_authority(&rule_admin, &update, None).is_err());
+        let update = PolicyUpdate {
+            json_rule: policy.json_rule.clone(),
+            update_delay: 60,
+            ..Default::default()
+        };
+        assert!(policy.is_rule_update(&update));
+        assert!(policy.check_update_authority(&rule_admin, &update, None).is_ok());
+        assert!(policy.check_update_authority(&royalty_admin, &update, None).is_err());
+    }
+
+    #[test]
+    fn test_frozen_policy() {
+        let mut policy = policy_fixture();
+        policy.json_rule = Some(r#"{"conditions":{"field":"policy/frozen","operator":"bool_equals","value":true},"events":[]}"#.to_string());
+        let mut action_ctx = action_ctx_fixture();
+        assert!(matches(&policy, &action_ctx).is_err()); // unknown without the policy facts
+        action_ctx.policy = Some((&policy).into());
+        assert!(matches(&policy, &action_ctx).is_err());
+
+        policy.frozen = true;
+        action_ctx.policy = Some((&policy).into());
+        assert!(matches(&policy, &action_ctx).is_ok());
+        let update = PolicyUpdate {
+            json_rule: policy.json_rule.clone(),
+            ..Default::default()
+        };
+        assert!(policy.update(update).is_err());
+    }
+
+    #[test]
+    fn test_paused_policy() {
+        let mut policy = policy_fixture();
+        let action_ctx = action_ctx_fixture();
+        assert!(matches(&policy, &action_ctx).is_ok());
+        policy.paused = true;
+        assert!(matches(&policy, &action_ctx).is_err());
+        policy.paused = false;
+        assert!(matches(&policy, &action_ctx).is_ok());
+    }
+
+    #[test]
+    fn test_policy_closable() {
+        let mut policy = policy_fixture();
+        assert!(!policy.is_closable()); // version 0, the accounts created before the account_count are unknown
+        policy.version = Policy::VERSION;
+        assert!(policy.is_closable());
+        policy.add_account().unwrap();
+        assert!(!policy.is_closable());
+        policy.remove_account();
+        policy.remove_account();
+        assert_eq!(policy.account_count, 0);
+        assert!(policy.is_closable());
+
+        // wrap, burn and migrate_to_mpl count the mints in the mint counter, not in the policy
+        let policy_key = Pubkey::new_unique();
+        let program_id = crate::id();
+        let mut policy_data = vec![];
+        policy.try_serialize(&mut policy_data).unwrap();
+        let mut policy_lamports = 0;
+        let policy_info = AccountInfo::new(&policy_key, false, false, &mut policy_lamports, &mut policy_data, &program_id, false, 0);
+        let policy_account = Account::<Policy>::try_from(&policy_info).unwrap();
+        assert!(MintCounter::record(&policy_account, true, &[]).is_err()); // the mint counter is required
+
+        let mut counter_data = vec![];
+        MintCounter {
+            policy: policy_key,
+            ..Default::default()
+        }
+        .try_serialize(&mut counter_data)
+        .unwrap();
+        let counter_key = Pubkey::new_unique();
+        let mut counter_lamports = 0;
+        let counter_info = AccountInfo::new(&counter_key, false, true, &mut counter_lamports, &mut counter_data, &program_id, false, 0);
+        let accounts = [counter_info];
+        MintCounter::record(&policy_account, true, &accounts).unwrap();
+        MintCounter::record(&policy_account, true, &accounts).unwrap();
+        MintCounter::record(&policy_account, false, &accounts).unwrap();
+        assert_eq!(MintCounter::load(&policy_key, &accounts).unwrap().1.count, 1);
+        assert!(MintCounter::load(&Pubkey::new_unique(), &accounts).is_err());
+    }
+
+    #[test]
+    fn test_policy_royalty_split_validation() {
+        let recipient = |share: u8| RoyaltyRecipient {
+            address: Pubkey::new_unique(),
+            share,
+        };
+
+        let mut policy = policy_fixture();
+        policy.royalty_split = Some(vec![recipient(100)]);
+        assert!(policy.valid().is_ok());
+
+        policy.royalty_split = Some(vec![recipient(50), recipient(30), recipient(10), recipient(5), recipient(5)]);
+        assert!(policy.valid().is_ok());
+
+        // too many recipients
+        policy.royalty_split = Some(vec![
+            recipient(50),
+            recipient(30),
+            recipient(10),
+            recipient(5),
+            recipient(4),
+            recipient(1),
+        ]);
+        assert!(policy.valid().is_err());
+
+        // no recipients
+        policy.royalty_split = Some(vec![]);
+        assert!(policy.valid().is_err());
+
+        // shares must sum to 100
+        policy.royalty_split = Some(vec![recipient(50), recipient(49)]);
+        assert!(policy.valid().is_err());
+        policy.royalty_split = Some(vec![recipient(100), recipient(1)]);
+        assert!(policy.valid().is_err());
+
+        // duplicated recipients
+        let duplicated = recipient(50);
+        policy.royalty_split = Some(vec![duplicated.clone(), duplicated]);
+        assert!(policy.valid().is_err());
+    }
+
+    #[test]
+    fn test_royalty_exemption() {
+        let wallet = Pubkey::new_unique();
+        let program_id = Pubkey::new_unique();
+        let other = Pubkey::new_unique();
+        let royalty_exemption = RoyaltyExemption {
+            wallets: vec![wallet],
+            program_ids: vec![program_id],
+            ..Default::default()
+        };
+        assert!(royalty_exemption.valid().is_ok());
+        assert!(royalty_exemption.is_exempted(Some(&wallet), Some(&other), None));
+        assert!(royalty_exemption.is_exempted(Some(&other), Some(&wallet), None));
+        assert!(royalty_exemption.is_exempted(Some(&other), Some(&other), Some(&program_id)));
+        assert!(!royalty_exemption.is_exempted(Some(&other), Some(&other), None));
+        assert!(!royalty_exemption.is_exempted(Some(&program_id), Some(&other), Some(&wallet)));
+        assert!(!royalty_exemption.is_exempted(None, None, None));
+
+        let royalty_exemption = RoyaltyExemption {
+            wallets: vec![wallet; RoyaltyExemption::MAX_ENTRIES + 1],
+            ..Default::default()
+        };
+        assert!(royalty_exemption.valid().is_err());
+    }
+
+    #[test]
+    fn test_compiled_rule_same_as_json_rule() {
+        let mut policy = policy_fixture();
+        policy.compile_rule();
+        let compiled_rule = policy.compiled_rule.clone().unwrap();
+        let data = compiled_rule.try_to_vec().unwrap();
+        assert_eq!(CompiledRule::try_from_slice(&data).unwrap(), compiled_rule);
+
+        // rules that can't be compiled are left to the json rules engine
+        policy.json_rule = Some(r#"{"conditions":{"field":"mint_state","operator":"string_equals","value":""},"events":[]}"#.to_string());
+        policy.compile_rule();
+        assert!(policy.compiled_rule.is_none());
+        policy.json_rule =
+            Some(r#"{"conditions":{"field":"metadata/seller_fee_basis_points","operator":"float_equals","value":1.0},"events":[]}"#.to_string());
+        policy.compile_rule();
+        assert!(policy.compiled_rule.is_none());
+        policy.json_rule = None;
+        policy.compile_rule();
+        assert!(policy.compiled_rule.is_none());
+
+        let mut action_ctx = action_ctx_fixture();
+        action_ctx.program_ids = vec![Pubkey::new_unique().to_string()];
+        action_ctx.last_memo_data = None;
+        for json_rule in [
+            // missing metadata is unknown, and not unknown is still unknown
+            r#"{"conditions":{"field":"metadata/name","operator":"string_equals","value":""},"events":[]}"#,
+            r#"{"conditions":{"not":{"field":"metadata/name","operator":"string_equals","value":""}},"events":[]}"#,
+            r#"{"conditions":{"or":[{"field":"metadata/name","operator":"string_equals","value":""},{"field":"action","operator":"string_equals","value":"transfer"}]},"events":[]}"#,
+            // null and mismatched types are not met
+            r#"{"conditions":{"field":"last_memo_data","operator":"string_not_equals","value":"x"},"events":[]}"#,
+            r#"{"conditions":{"not":{"field":"last_memo_data","operator":"string_not_equals","value":"x"}},"events":[]}"#,
+            r#"{"conditions":{"field":"program_ids","operator":"int_does_not_contain","value":1},"events":[]}"#,
+            r#"{"conditions":{"field":"action","operator":"int_equals","value":1},"events":[]}"#,
+            r#"{"conditions":{"field":"/mint_state/transferred_count","operator":"int_in_range","value":[0, 1]},"events":[]}"#,
+            r#"{"conditions":{"should_minimum_meet":2,"conditions":[{"field":"action","operator":"string_equals","value":"transfer"},{"field":"to","operator":"string_equals","value":""},{"field":"mint_state/version","operator":"int_equals","value":0}]},"events":[]}"#,
+        ] {
+            policy.json_rule = Some(json_rule.to_string());
+            assert!(policy.valid().is_ok());
+            let _ = matches(&policy, &action_ctx);
+        }
+    }
+
+    #[test]
+    fn test_rule_chunk() {
+        // an allowlist that doesn't fit in the policy's json_rule
+        let program_ids = (0..100).map(|_| Pubkey::new_unique().to_string()).collect::<Vec<String>>();
+        let json_rule = r#"{"conditions":{"field":"program_ids","operator":"string_is_subset","value":[PLACEHOLDER]},"events":[]}"#.replace(
+            "PLACEHOLDER",
+            &program_ids.iter().map(|x| format!("\"{}\"", x)).collect::<Vec<String>>().join(","),
+        );
+        assert!(json_rule.len() > Policy::JSON_RULE_MAX_LEN);
+        assert!(json_rule.len() <= RuleChunk::JSON_RULE_MAX_LEN);
+        let rule_chunk = RuleChunk {
+            json_rule: json_rule.into_bytes(),
+            ..Default::default()
+        };
+        assert!(rule_chunk.valid().is_ok());
+
+        let mut action_ctx = action_ctx_fixture();
+        action_ctx.program_ids = vec![program_ids[42].clone()];
+        assert!(rule_chunk.evaluate(&action_ctx, &[]).is_ok());
+        action_ctx.program_ids = vec![Pubkey::new_unique().to_string()];
+        assert!(rule_chunk.evaluate(&action_ctx, &[]).is_err());
+
+        // incomplete json
+        let rule_chunk = RuleChunk {
+            json_rule: br#"{"conditions":{"field":"program_ids""#.to_vec(),
+            ..Default::default()
+        };
+        assert!(rule_chunk.evaluate(&action_ctx, &[]).is_err());
+        assert!(rule_chunk.valid().is_err());
+
+        // the chunks must be passed in the remaining accounts
+        assert!(RuleChunk::load(&Pubkey::new_unique(), 0, &[]).is_err());
+        let mut policy = policy_fixture();
+        policy.rule_chunk_count = 1;
+        assert!(policy.check_new_rule_chunks(1, &[]).is_ok()); // already referenced
+        assert!(policy.check_new_rule_chunks(0, &[]).is_err());
+        policy.rule_chunk_count = Policy::RULE_CHUNKS_MAX + 1;
+        assert!(policy.valid().is_err());
+    }
+
+    #[test]
+    fn test_list_account_operators() {
+        let mut keys = (0..50).map(|_| Pubkey::new_unique()).collect::<Vec<Pubkey>>();
+        keys.sort();
+        let list = ListAccount {
+            keys: keys.clone(),
+            ..Default::default()
+        };
+        assert!(list.valid().is_ok());
+        let mut data = vec![];
+        list.try_serialize(&mut data).unwrap();
+
+        let list_key = Pubkey::new_unique();
+        let program_id = crate::id();
+        let mut lamports = 0;
+        let list_account = AccountInfo::new(&list_key, false, false, &mut lamports, &mut data, &program_id, false, 0);
+        let accounts = [list_account];
+        assert_eq!(ListAccount::contains(&list_key, &keys[0], &accounts, false), Some(true));
+        assert_eq!(ListAccount::contains(&list_key, &keys[49], &accounts, false), Some(true));
+        assert!(keys.iter().all(|x| ListAccount::contains(&list_key, x, &accounts, false) == Some(true)));
+        assert_eq!(ListAccount::contains(&list_key, &Pubkey::new_unique(), &accounts, false), Some(false));
+        assert_eq!(ListAccount::contains(&Pubkey::new_unique(), &keys[0], &accounts, false), None);
+
+        // a frozen policy only trusts the lists that can't change anymore
+        assert_eq!(ListAccount::contains(&list_key, &keys[0], &accounts, true), Some(true)); // the default authority
+        let mutable_list = ListAccount {
+            authority: Pubkey::new_unique(),
+            keys: keys.clone(),
+            ..Default::default()
+        };
+        let mut mutable_data = vec![];
+        mutable_list.try_serialize(&mut mutable_data).unwrap();
+        let mutable_key = Pubkey::new_unique();
+        let mut mutable_lamports = 0;
+        let mutable_account = AccountInfo::new(
+            &mutable_key,
+            false,
+            false,
+            &mut mutable_lamports,
+            &mut mutable_data,
+            &program_id,
+            false,
+            0,
+        );
+        let mutable_accounts = [mutable_account];
+        assert_eq!(ListAccount::contains(&mutable_key, &keys[0], &mutable_accounts, false), Some(true));
+        assert_eq!(ListAccount::contains(&mutable_key, &keys[0], &mutable_accounts, true), None);
+
+        let mut policy = policy_fixture();
+        policy.json_rule = Some(
+            r#"{"conditions":{"and":[{"field":"program_ids","operator":"all_in_list","value":"LIST"},{"field":"to","operator":"not_in_list","value":"LIST"}]},"events":[]}"#
+                .replace("LIST", &list_key.to_string()),
+        );
+        assert!(policy.valid().is_ok());
+        policy.compile_rule();
+        assert!(policy.compiled_rule.is_some());
+
+        let mut action_ctx = action_ctx_fixture();
+        action_ctx.program_ids = vec![keys[1].to_string(), keys[2].to_string()];
+        assert!(policy.matches(&action_ctx, &accounts).is_ok());
+        assert!(policy.matches(&action_ctx, &[]).is_err()); // the list must be passed
+
+        action_ctx.program_ids = vec![keys[1].to_string(), Pubkey::new_unique().to_string()];
+        assert!(policy.matches(&action_ctx, &accounts).is_err());
+
+        let mut action_ctx = action_ctx_fixture();
+        action_ctx.to = Some(keys[3].to_string());
+        assert!(policy.matches(&action_ctx, &accounts).is_err());
+
+        // freeze_policy needs the lists that the rules reference, frozen
+        assert!(policy.check_frozen_lists(&accounts).is_ok());
+        assert!(policy.check_frozen_lists(&[]).is_err());
+        let mut mutable_policy = policy.clone();
+        mutable_policy.json_rule = Some(
+            r#"{"conditions":{"not":{"field":"to","operator":"in_list","value":"LIST"}},"events":[]}"#.replace("LIST", &mutable_key.to_string()),
+        );
+        mutable_policy.compile_rule();
+        assert!(mutable_policy.check_frozen_lists(&mutable_accounts).is_err());
+
+        // the list operators need a valid list address
+        policy.json_rule = Some(r#"{"conditions":{"field":"to","operator":"in_list","value":"LIST"},"events":[]}"#.to_string());
+        assert!(policy.valid().is_err());
+
+        // unsorted or duplicated keys
+        let mut list = list;
+        list.keys.swap(0, 1);
+        assert!(list.valid().is_err());
+        list.keys.swap(0, 1);
+        list.keys[1] = list.keys[0];
+        assert!(list.valid().is_err());
+    }
+
+    #[test]
+    fn test_merkle_allowlist() {
+        let keys = (0..7).map(|_| Pubkey::new_unique()).collect::<Vec<Pubkey>>();
+        let tree = MerkleTree::new(&keys);
+        for key in &keys {
+            assert!(tree.get_proof(key).unwrap().verify(&tree.root()));
+        }
+        assert!(tree.get_proof(&Pubkey::new_unique()).is_none());
+        let mut forged = tree.get_proof(&keys[0]).unwrap();
+        forged.leaf = Pubkey::new_unique();
+        assert!(!forged.verify(&tree.root()));
+
+        let mut policy = policy_fixture();
+        policy.json_rule = Some(
+            r#"{"conditions":{"and":[{"field":"to","operator":"in_merkle_tree","value":"ROOT"},{"field":"program_ids","operator":"all_in_merkle_tree","value":"ROOT"}]},"events":[]}"#
+                .replace("ROOT", &tree.root_string()),
+        );
+        assert!(policy.valid().is_ok());
+        policy.compile_rule();
+        assert!(policy.compiled_rule.is_some());
+
+        // the proofs from the instruction data
+        let mut action_ctx = action_ctx_fixture();
+        action_ctx.to = Some(keys[2].to_string());
+        action_ctx.program_ids = vec![keys[6].to_string()];
+        assert!(policy.matches(&action_ctx, &[]).is_err()); // without the proofs
+        action_ctx.merkle_proofs = vec![tree.get_proof(&keys[2]).unwrap(), tree.get_proof(&keys[6]).unwrap()];
+        assert!(policy.matches(&action_ctx, &[]).is_ok());
+
+        // the proofs from the memo
+        let mut action_ctx = action_ctx_fixture();
+        action_ctx.to = Some(keys[2].to_string());
+        let memo = MerkleTree::to_memo(&[tree.get_proof(&keys[2]).unwrap()]);
+        action_ctx.merkle_proofs = MerkleProofMemo::parse(&memo);
+        assert!(policy.matches(&action_ctx, &[]).is_ok());
+
+        // a proof of another tree
+        let other_tree = MerkleTree::new(&[keys[2], Pubkey::new_unique()]);
+        action_ctx.merkle_proofs = vec![other_tree.get_proof(&keys[2]).unwrap()];
+        assert!(policy.matches(&action_ctx, &[]).is_err());
+    }
+
+    #[test]
+    fn test_action_rules() {
+        let frozen = r#"{"conditions":{"not":{"field":"metadata/name","operator":"string_has_substring","value":"FROZEN"}},"events":[]}"#;
+        let mut policy = policy_fixture();
+        policy.json_rule = Some(
+            r#"{"conditions":{"field":"to","operator":"string_not_equals","value":"DWuopEsTrg5qWMSMVT1hoiVTRQG9PkGJZSbXiKAxHYbn"},"events":[]}"#
+                .to_owned(),
+        );
+        policy.action_rules = vec![
+            ActionRule {
+                action: "transfer".to_owned(),
+                json_rule: frozen.to_owned(),
+                compiled_rule: None,
+            },
+            ActionRule {
+                action: "burn".to_owned(),
+                json_rule: "".to_owned(), // burn is always allowed
+                compiled_rule: None,
+            },
+        ];
+        assert!(policy.valid().is_ok());
+
+        let mut action_ctx = action_ctx_fixture();
+        let mut metadata = metadata_ctx_fixture();
+        metadata.name = "abc FROZEN".to_owned();
+        action_ctx.metadata = Some(metadata);
+        action_ctx.to = Some("DWuopEsTrg5qWMSMVT1hoiVTRQG9PkGJZSbXiKAxHYbn".to_owned());
+        assert!(matches(&policy, &action_ctx).is_err()); // the transfer rule, not the default one
+
+        action_ctx.action = "burn".to_owned();
+        assert!(matches(&policy, &action_ctx).is_ok());
+
+        action_ctx.action = "approve".to_owned();
+        assert!(matches(&policy, &action_ctx).is_err()); // the default rule
+
+        action_ctx.to = Some(Pubkey::new_unique().to_string());
+        assert!(matches(&policy, &action_ctx).is_ok());
+
+        // unknown or duplicated actions
+        policy.action_rules[1].action = "transfer".to_owned();
+        assert!(policy.valid().is_err());
+        policy.action_rules[1].action = "sell".to_owned();
+        assert!(policy.valid().is_err());
+
+        // the json_rule and the action_rules share the size budget
+        policy.action_rules[1].action = "burn".to_owned();
+        policy.action_rules[1].json_rule = format!(
+            r#"{{"conditions":{{"field":"to","operator":"string_not_in","value":["{}"]}},"events":[]}}"#,
+            vec!["DWuopEsTrg5qWMSMVT1hoiVTRQG9PkGJZSbXiKAxHYbn"; 20].join(r#"",""#)
+        );
+        assert!(policy.valid().is_err());
+    }
+
+    #[test]
+    fn test_rule_trace() {
+        let json_rule = r#"{"conditions":{"and":[{"field":"action","operator":"string_equals","value":"transfer"},{"not":{"field":"mint_state/transferred_count","operator":"int_less_than","value":3}}]},"events":[]}"#;
+        let action_ctx = action_ctx_fixture();
+        let (status, trace) = trace_json_rule(json_rule.as_bytes(), &action_ctx, &[]).unwrap();
+        assert_eq!(status, Status::NotMet);
+        assert_eq!(
+            trace.iter().map(|x| (x.depth, x.fact.as_deref(), x.status)).collect::<Vec<_>>(),
+            vec![
+                (0, None, TRACE_STATUS_NOT_MET),
+                (1, Some("transfer"), TRACE_STATUS_MET),
+                (1, None, TRACE_STATUS_NOT_MET),
+                (2, Some("0"), TRACE_STATUS_MET),
+            ]
+        );
+        assert_eq!(trace[3].name, "mint_state/transferred_count IntLessThan(3)");
+
+        // the json rules engine only tells the names and the statuses
+        let json_rule = r#"{"conditions":{"field":"metadata/seller_fee_basis_points","operator":"float_equals","value":1.0},"events":[]}"#;
+        let (status, trace) = trace_json_rule(json_rule.as_bytes(), &action_ctx, &[]).unwrap();
+        assert_eq!(status, Status::Unknown);
+        assert_eq!(trace.len(), 1);
+        assert_eq!(trace[0].status, TRACE_STATUS_UNKNOWN);
+    }
+
+    #[test]
+    fn test_client_matches() {
+        let allowed = Pubkey::new_unique();
+        let list = ListAccount {
+            keys: vec![allowed],
+            ..Default::default()
+        };
+        let mut data = vec![];
+        list.try_serialize(&mut data).unwrap();
+        let list_key = Pubkey::new_unique();
+        let mut accounts = vec![ClientAccount {
+            key: list_key,
+            owner: crate::id(),
+            data,
+        }];
+
+        let mut policy = policy_fixture();
+        policy.json_rule = Some(
+            r#"{"conditions":{"and":[{"field":"to","operator":"in_list","value":"LIST"},{"field":"mint_state/derived_cooldown","operator":"int_greater_than_inclusive","value":3600}]},"events":[]}"#
+                .replace("LIST", &list_key.to_string()),
+        );
+        policy.compile_rule();
+        assert!(policy.valid().is_ok());
+
+        let mut action = ClientAction {
+            action: "transfer".to_string(),
+            mint: Pubkey::new_unique(),
+            mint_state: MintState {
+                last_approved_at: 1_000_000,
+                last_transferred_at: 1_000_000,
+                ..Default::default()
+            },
+            to: Some(allowed),
+            now: 1_000_000 + 3600,
+            ..Default::default()
+        };
+        assert!(client::matches(&policy, &action.to_action_ctx(), &mut accounts).is_ok());
+        assert!(client::matches(&policy, &action.to_action_ctx(), &mut []).is_err()); // the list must be passed
+
+        action.now -= 1; // the cooldown has not elapsed
+        assert!(client::matches(&policy, &action.to_action_ctx(), &mut accounts).is_err());
+        action.now += 1;
+        action.to = Some(Pubkey::new_unique());
+        assert!(client::matches(&policy, &action.to_action_ctx(), &mut accounts).is_err());
+    }
+
+    #[test]
+    fn test_rule_events() {
+        let uuid = Pubkey::new_unique();
+        let (policy_key, bump) = Pubkey::find_program_address(&[Policy::SEED.as_bytes(), uuid.as_ref()], &crate::id());
+        let mut policy = policy_fixture();
+        policy.uuid = uuid;
+        policy.bump = [bump];
+        let recipient = Pubkey::new_unique();
+        policy.json_rule = Some(
+            r#"{"conditions":{"field":"to","operator":"string_not_equals","value":"DWuopEsTrg5qWMSMVT1hoiVTRQG9PkGJZSbXiKAxHYbn"},"events":[
+                {"type":"emit_error","params":{"message":"transfers to DWuop are not allowed"}},
+                {"type":"require_memo","params":{}},
+                {"type":"charge_fee","params":{"lamports":1000,"recipient":"RECIPIENT"}},
+                {"type":"increment_counter","params":{"index":0}},
+                {"type":"notify","params":{"channel":"off-chain"}}
+            ]}"#
+            .replace("RECIPIENT", &recipient.to_string()),
+        );
+        assert!(policy.valid().is_ok());
+        policy.compile_rule();
+        assert_eq!(
+            policy.compiled_rule.as_ref().unwrap().events,
+            vec![
+                RuleEvent::EmitError("transfers to DWuop are not allowed".to_string()),
+                RuleEvent::RequireMemo,
+                RuleEvent::ChargeFee(1000, recipient),
+                RuleEvent::IncrementCounter(0),
+            ]
+        );
+
+        let mut action_ctx = action_ctx_fixture();
+        assert!(matches(&policy, &action_ctx).is_err()); // the memo is required
+        action_ctx.last_memo_data = Some("hello".to_string());
+        assert_eq!(policy.evaluate(&action_ctx, &[]).unwrap().len(), 4);
+        action_ctx.to = Some("DWuopEsTrg5qWMSMVT1hoiVTRQG9PkGJZSbXiKAxHYbn".to_string());
+        assert!(matches(&policy, &action_ctx).is_err());
+
+        // the counter is incremented, the fee needs the payer and the recipient in the remaining accounts
+        policy.json_rule = Some(r#"{"conditions":{"and":[]},"events":[{"type":"increment_counter","params":{"index":0}}]}"#.to_string());
+        policy.compile_rule();
+        let mut data = vec![];
+        RuleCounter {
+            policy: policy_key,
+            ..Default::default()
+        }
+        .try_serialize(&mut data)
+        .unwrap();
+        let counter_key = Pubkey::new_unique();
+        let program_id = crate::id();
+        let mut lamports = 0;
+        let counter = AccountInfo::new(&counter_key, false, true, &mut lamports, &mut data, &program_id, false, 0);
+        let accounts = [counter];
+        assert!(policy.matches(&action_ctx, &accounts).is_ok());
+        assert!(policy.matches(&action_ctx, &accounts).is_ok());
+        assert_eq!(RuleCounter::load(&policy_key, 0, &accounts).unwrap().1.count, 2);
+        assert!(policy.matches(&action_ctx, &[]).is_err());
+
+        policy.json_rule = Some(format!(
+            r#"{{"conditions":{{"and":[]}},"events":[{{"type":"charge_fee","params":{{"lamports":1000,"recipient":"{}"}}}}]}}"#,
+            recipient
+        ));
+        policy.compile_rule();
+        assert!(policy.matches(&action_ctx, &accounts).is_err());
+
+        // invalid events of the known types
+        policy.json_rule =
+            Some(r#"{"conditions":{"and":[]},"events":[{"type":"charge_fee","params":{"lamports":1000,"recipient":"x"}}]}"#.to_string());
+        assert!(policy.valid().is_err());
+        policy.json_rule = Some(r#"{"conditions":{"and":[]},"events":[{"type":"require_memo"}]}"#.to_string());
+        assert!(policy.valid().is_err());
+    }
+
+    #[test]
+    fn test_condition_errors() {
+        let mut policy = policy_fixture();
+        policy.json_rule = Some(
+            r#"{"conditions":{"and":[
+                {"field":"action","operator":"string_not_equals","value":"burn","error":{"code":1,"message":"burning is disabled"}},
+                {"or":[
+                    {"field":"to","operator":"string_not_equals","value":"DWuopEsTrg5qWMSMVT1hoiVTRQG9PkGJZSbXiKAxHYbn","error":{"code":2,"message":"blocked recipient"}},
+                    {"field":"action","operator":"string_equals","value":"burn"}
+                ],"error":{"code":3,"message":"transfer denied"}}
+            ]},"events":[]}"#
+                .to_string(),
+        );
+        assert!(policy.valid().is_ok());
+
+        let error_code = |action_ctx: &ActionCtx| match matches(&policy, action_ctx) {
+            Err(Error::AnchorError(e)) => Some(e.error_code_number),
+            _ => None,
+        };
+        let mut action_ctx = action_ctx_fixture();
+        assert_eq!(error_code(&action_ctx), None);
+        action_ctx.action = "burn".to_string();
+        assert_eq!(error_code(&action_ctx), Some(7001));
+        action_ctx.action = "transfer".to_string();
+        action_ctx.to = Some("DWuopEsTrg5qWMSMVT1hoiVTRQG9PkGJZSbXiKAxHYbn".to_string());
+        assert_eq!(error_code(&action_ctx), Some(7002)); // the innermost error
+
+        // the code must fit in the reserved range
+        policy.json_rule = Some(
+            r#"{"conditions":{"field":"action","operator":"string_not_equals","value":"burn","error":{"code":1000,"message":"x"}},"events":[]}"#
+                .to_string(),
+        );
+        assert!(policy.valid().is_err());
     }
 
     #[test]
@@ -274,7 +1035,7 @@ mod tests {
         let action_ctx = action_ctx_fixture();
 
         assert!(policy.valid().is_ok());
-        assert!(policy.matches(&action_ctx).is_ok());
+        assert!(matches(&policy, &action_ctx).is_ok());
     }
 
     #[test]
@@ -291,12 +1052,12 @@ mod tests {
         let mut action_ctx = action_ctx_fixture();
         action_ctx.program_ids = vec![program_id];
         assert!(policy.valid().is_ok());
-        assert!(policy.matches(&action_ctx).is_ok());
+        assert!(matches(&policy, &action_ctx).is_ok());
 
         let mut action_ctx = action_ctx_fixture();
         action_ctx.program_ids = vec![Pubkey::new_unique().to_string()];
         assert!(policy.valid().is_ok());
-        assert!(policy.matches(&action_ctx).is_err());
+        assert!(matches(&policy, &action_ctx).is_err());
     }
 
     #[test]
@@ -312,12 +1073,12 @@ mod tests {
         let mut action_ctx = action_ctx_fixture();
         action_ctx.program_ids = vec![program_id];
         assert!(policy.valid().is_ok());
-        assert!(policy.matches(&action_ctx).is_err());
+        assert!(matches(&policy, &action_ctx).is_err());
 
         let mut action_ctx = action_ctx_fixture();
         action_ctx.program_ids = vec![Pubkey::new_unique().to_string()];
         assert!(policy.valid().is_ok());
-        assert!(policy.matches(&action_ctx).is_ok());
+        assert!(matches(&policy, &action_ctx).is_ok());
 
         let program_id = Pubkey::new_unique().to_string();
         let mut policy = policy_fixture();
@@ -326,7 +1087,7 @@ mod tests {
         let mut action_ctx = action_ctx_fixture();
         action_ctx.program_ids = vec![program_id];
         assert!(policy.valid().is_ok());
-        assert!(policy.matches(&action_ctx).is_ok());
+        assert!(matches(&policy, &action_ctx).is_ok());
 
         let program_id = Pubkey::new_unique().to_string();
         let mut policy = policy_fixture();
@@ -336,7 +1097,7 @@ mod tests {
         let mut action_ctx = action_ctx_fixture();
         action_ctx.program_ids = vec![program_id];
         assert!(policy.valid().is_ok());
-        assert!(policy.matches(&action_ctx).is_ok());
+        assert!(matches(&policy, &action_ctx).is_ok());
     }
 
     #[test]
@@ -354,22 +1115,22 @@ mod tests {
         // ok with just allowed_program_ids[0]
         let mut action_ctx = action_ctx_fixture();
         action_ctx.program_ids = vec![allowed_program_ids[0].clone()];
-        assert!(policy.matches(&action_ctx).is_ok());
+        assert!(matches(&policy, &action_ctx).is_ok());
 
         // ok with just allowed_program_ids[1]
         let mut action_ctx = action_ctx_fixture();
         action_ctx.program_ids = vec![allowed_program_ids[1].clone()];
-        assert!(policy.matches(&action_ctx).is_ok());
+        assert!(matches(&policy, &action_ctx).is_ok());
 
         // not ok with some other program_id
         let mut action_ctx = action_ctx_fixture();
         action_ctx.program_ids = vec![Pubkey::new_unique().to_string()];
-        assert!(policy.matches(&action_ctx).is_err());
+        assert!(matches(&policy, &action_ctx).is_err());
 
         // not ok with some other program_id and allowed_program_ids[0]
         let mut action_ctx = action_ctx_fixture();
         action_ctx.program_ids = vec![Pubkey::new_unique().to_string(), allowed_program_ids.clone()[0].clone()];
-        assert!(policy.matches(&action_ctx).is_err());
+        assert!(matches(&policy, &action_ctx).is_err());
     }
 
     #[test]
@@ -382,7 +1143,7 @@ mod tests {
         policy.json_rule =
             Some(r#"{"conditions":{"field":"metadata/name","operator":"string_has_substring","value":"FROZEN"},"events":[]}"#.to_owned());
         assert!(policy.valid().is_ok());
-        assert!(policy.matches(&action_ctx).is_ok());
+        assert!(matches(&policy, &action_ctx).is_ok());
 
         let mut action_ctx = action_ctx_fixture();
         let mut metadata = metadata_ctx_fixture();
@@ -392,7 +1153,7 @@ mod tests {
         policy.json_rule =
             Some(r#"{"events":[],"conditions":{"or":[{"field":"action","operator":"string_not_equals","value":"transfer"},{"and":[{"not":{"field":"metadata/name","operator":"string_has_substring","value":"FROZEN"}},{"or":[{"field":"to","operator":"string_not_equals","value":"DWuopEsTrg5qWMSMVT1hoiVTRQG9PkGJZSbXiKAxHYbn"},{"field":"metadata/name","operator":"string_has_substring","value":"WINNER"}]}]}]}}"#.to_owned());
         assert!(policy.valid().is_ok());
-        assert!(policy.matches(&action_ctx).is_ok());
+        assert!(matches(&policy, &action_ctx).is_ok());
 
         let mut action_ctx = action_ctx_fixture();
         let mut metadata = metadata_ctx_fixture();
@@ -402,7 +1163,7 @@ mod tests {
         policy.json_rule =
             Some(r#"{"events":[],"conditions":{"or":[{"field":"action","operator":"string_not_equals","value":"transfer"},{"and":[{"not":{"field":"metadata/name","operator":"string_has_substring","value":"FROZEN"}},{"or":[{"field":"to","operator":"string_not_equals","value":"DWuopEsTrg5qWMSMVT1hoiVTRQG9PkGJZSbXiKAxHYbn"},{"field":"metadata/name","operator":"string_has_substring","value":"WINNER"}]}]}]}}"#.to_owned());
         assert!(policy.valid().is_ok());
-        assert!(policy.matches(&action_ctx).is_err());
+        assert!(matches(&policy, &action_ctx).is_err());
 
         let mut action_ctx = action_ctx_fixture();
         let mut metadata = metadata_ctx_fixture();
@@ -413,7 +1174,7 @@ mod tests {
         policy.json_rule =
             Some(r#"{"events":[],"conditions":{"or":[{"field":"action","operator":"string_not_equals","value":"transfer"},{"and":[{"not":{"field":"metadata/name","operator":"string_has_substring","value":"FROZEN"}},{"or":[{"field":"to","operator":"string_not_equals","value":"DWuopEsTrg5qWMSMVT1hoiVTRQG9PkGJZSbXiKAxHYbn"},{"field":"metadata/name","operator":"string_has_substring","value":"WINNER"}]}]}]}}"#.to_owned());
         assert!(policy.valid().is_ok());
-        assert!(policy.matches(&action_ctx).is_err());
+        assert!(matches(&policy, &action_ctx).is_err());
 
         let mut action_ctx = action_ctx_fixture();
         let mut metadata = metadata_ctx_fixture();
@@ -424,7 +1185,7 @@ mod tests {
         policy.json_rule =
             Some(r#"{"events":[],"conditions":{"or":[{"field":"action","operator":"string_not_equals","value":"transfer"},{"and":[{"not":{"field":"metadata/name","operator":"string_has_substring","value":"FROZEN"}},{"or":[{"field":"to","operator":"string_not_equals","value":"DWuopEsTrg5qWMSMVT1hoiVTRQG9PkGJZSbXiKAxHYbn"},{"field":"metadata/name","operator":"string_has_substring","value":"WINNER"}]}]}]}}"#.to_owned());
         assert!(policy.valid().is_ok());
-        assert!(policy.matches(&action_ctx).is_ok());
+        assert!(matches(&policy, &action_ctx).is_ok());
 
         let mut action_ctx = action_ctx_fixture();
         let mut metadata = metadata_ctx_fixture();
@@ -436,7 +1197,7 @@ mod tests {
         policy.json_rule =
             Some(r#"{"events":[],"conditions":{"or":[{"field":"action","operator":"string_not_equals","value":"transfer"},{"and":[{"not":{"field":"metadata/name","operator":"string_has_substring","value":"FROZEN"}},{"or":[{"field":"to","operator":"string_not_equals","value":"DWuopEsTrg5qWMSMVT1hoiVTRQG9PkGJZSbXiKAxHYbn"},{"field":"metadata/name","operator":"string_has_substring","value":"WINNER"}]}]}]}}"#.to_owned());
         assert!(policy.valid().is_ok());
-        assert!(policy.matches(&action_ctx).is_ok());
+        assert!(matches(&policy, &action_ctx).is_ok());
     }
 
     #[test]
@@ -454,7 +1215,7 @@ mod tests {
             .into(),
         );
         assert!(policy.valid().is_ok());
-        assert!(policy.matches(&action_ctx).is_ok());
+        assert!(matches(&policy, &action_ctx).is_ok());
 
         let mut policy = policy_fixture();
         policy.json_rule = Some(
@@ -464,7 +1225,7 @@ mod tests {
             .into(),
         );
         assert!(policy.valid().is_ok());
-        assert!(policy.matches(&action_ctx).is_ok());
+        assert!(matches(&policy, &action_ctx).is_ok());
 
         let mut policy = policy_fixture();
         policy.json_rule = Some(
@@ -474,7 +1235,7 @@ mod tests {
             .into(),
         );
         assert!(policy.valid().is_ok());
-        assert!(policy.matches(&action_ctx).is_err());
+        assert!(matches(&policy, &action_ctx).is_err());
     }
 
     #[test]
@@ -489,7 +1250,7 @@ mod tests {
             .to_string(),
         );
         assert!(policy.valid().is_ok());
-        assert!(policy.matches(&action_ctx).is_ok());
+        assert!(matches(&policy, &action_ctx).is_ok());
 
         let mut action_ctx = action_ctx_fixture();
         action_ctx.mint_state.derived_datetime = 100.into();
@@ -499,7 +1260,7 @@ mod tests {
           {"conditions":{"and": [{"field":"mint_state/derived_datetime/utc_timestamp","operator":"int_greater_than","value":90}, {"field":"action","operator":"string_equals","value":"transfer"}]},"events":[]}
         "#.to_string());
         assert!(policy.valid().is_ok());
-        assert!(policy.matches(&action_ctx).is_ok());
+        assert!(matches(&policy, &action_ctx).is_ok());
 
         let mut action_ctx = action_ctx_fixture();
         action_ctx.mint_state.derived_datetime = 100.into();
@@ -509,7 +1270,7 @@ mod tests {
           {"conditions":{"and": [{"field":"mint_state/derived_datetime/utc_timestamp","operator":"int_greater_than","value":110}, {"field":"action","operator":"string_equals","value":"transfer"}]},"events":[]}
         "#.to_string());
         assert!(policy.valid().is_ok());
-        assert!(policy.matches(&action_ctx).is_err());
+        assert!(matches(&policy, &action_ctx).is_err());
 
         let mut action_ctx = action_ctx_fixture();
         action_ctx.mint_state.derived_datetime = 100.into();
@@ -521,7 +1282,7 @@ mod tests {
             .to_string(),
         );
         assert!(policy.valid().is_ok());
-        assert!(policy.matches(&action_ctx).is_ok());
+        assert!(matches(&policy, &action_ctx).is_ok());
 
         let mut action_ctx = action_ctx_fixture();
         action_ctx.mint_state.derived_datetime = (100 + 3600 * 12).into();
@@ -533,6 +1294,6 @@ mod tests {
             .to_string(),
         );
         assert!(policy.valid().is_ok());
-        assert!(policy.matches(&action_ctx).is_err());
+        assert!(matches(&policy, &action_ctx).is_err());
     }
 }
@@ -0,0 +1,115 @@
+// off-chain evaluation of the policies, with the same code as the program. The facts come from the fetched account
+// data and the cluster clock timestamp, instead of the Clock sysvar and the AccountInfo of an instruction
+use crate::action::{ActionCtx, MetadataCtx, MintAccountCtx, MintStateCtx, PolicyCtx};
+use crate::instructions::{RoyaltyQuote, RoyaltyQuoteRecipient};
+use crate::merkle::{MerkleProof, MerkleProofMemo};
+use crate::royalty::split_royalty_amount;
+use crate::rule::RuleEvent;
+use crate::state::{MintState, Policy};
+use anchor_lang::prelude::*;
+use anchor_spl::metadata::MetadataAccount;
+use anchor_spl::token::Mint;
+
+// an account fetched from the cluster, e.g. a rule chunk or a list account of the policy
+#[derive(Clone, Debug, Default)]
+pub struct ClientAccount {
+    pub key: Pubkey,
+    pub owner: Pubkey,
+    pub data: Vec<u8>,
+}
+
+// the facts of an action, as the program would see them in the instruction
+#[derive(Default)]
+pub struct ClientAction {
+    pub action: String,
+    pub mint: Pubkey,
+    pub mint_state: MintState,
+    pub mint_account: Option<Mint>,
+    pub metadata: Option<MetadataAccount>,
+    pub policy: Option<Policy>, // for the policy facts, e.g. policy/frozen
+    pub payer: Option<Pubkey>,
+    pub from: Option<Pubkey>,
+    pub to: Option<Pubkey>,
+    pub program_ids: Vec<Pubkey>, // the programs of all the instructions in the transaction
+    pub last_memo_signer: Option<Pubkey>,
+    pub last_memo_data: Option<String>,
+    pub merkle_proofs: Vec<MerkleProof>, // the proofs in the instruction data, the ones in the memo are parsed
+    pub now: i64,                        // the unix timestamp of the cluster clock
+}
+
+impl ClientAction {
+    pub fn to_action_ctx(&self) -> ActionCtx {
+        let mut merkle_proofs = self.merkle_proofs.clone();
+        if let Some(memo_data) = &self.last_memo_data {
+            merkle_proofs.extend(MerkleProofMemo::parse(memo_data));
+        }
+        ActionCtx {
+            action: self.action.clone(),
+            program_ids: self.program_ids.iter().map(|x| x.to_string()).collect(),
+            last_memo_data: self.last_memo_data.clone(),
+            last_memo_signer: self.last_memo_signer.map(|x| x.to_string()),
+            merkle_proofs,
+            payer: self.payer.map(|x| x.to_string()),
+            from: self.from.map(|x| x.to_string()),
+            to: self.to.map(|x| x.to_string()),
+            mint: self.mint.to_string(),
+            metadata: self.metadata.as_ref().map(MetadataCtx::from),
+            mint_account: self.mint_account.as_ref().map(MintAccountCtx::from),
+            mint_state: MintStateCtx::new(self.mint_state.clone(), self.now),
+            policy: self.policy.as_ref().map(PolicyCtx::from),
+        }
+    }
+}
+
+// same as Policy::matches, accounts are the rule chunks and the list accounts that the action would pass.
+// The rule events are returned instead of applied, e.g. to show the fees
+pub fn matches(policy: &Policy, ctx: &ActionCtx, accounts: &mut [ClientAccount]) -> Result<Vec<RuleEvent>> {
+    let mut lamports = vec![0; accounts.len()];
+    let account_infos = accounts
+        .iter_mut()
+        .zip(lamports.iter_mut())
+        .map(|(account, lamports)| AccountInfo::new(&account.key, false, false, lamports, &mut account.data, &account.owner, false, 0))
+        .collect::<Vec<_>>();
+    policy.evaluate(ctx, &account_infos)
+}
+
+// same as the royalty of transfer_with_payment. is_pass_holder is for DYNAMIC_ROYALTY_KIND_HOLDER_DISCOUNT, and
+// is_exempted is from RoyaltyExemption::is_exempted
+pub fn get_royalty_quote(
+    policy: &Policy,
+    metadata: &MetadataAccount,
+    mint_state: &MintState,
+    price: u64,
+    is_pass_holder: bool,
+    is_exempted: bool,
+    now: i64,
+) -> Result<RoyaltyQuote> {
+    let (royalty_bp, royalty_amount) = match is_exempted {
+        true => (0, 0),
+        false => {
+            let royalty_bp = policy.get_royalty_bp(
+                price,
+                mint_state.get_holding_duration_at(now),
+                is_pass_holder,
+                metadata.seller_fee_basis_points,
+            );
+            (royalty_bp, policy.get_royalty_amount(price, royalty_bp)?)
+        }
+    };
+    let recipients = policy.get_royalty_recipients(metadata);
+    let amounts = split_royalty_amount(royalty_amount, &recipients.iter().map(|x| x.share).collect::<Vec<u8>>())?;
+
+    Ok(RoyaltyQuote {
+        price_mint: policy.get_price_mint(),
+        royalty_bp,
+        royalty_amount,
+        recipients: recipients
+            .iter()
+            .zip(amounts)
+            .map(|(recipient, amount)| RoyaltyQuoteRecipient {
+                address: recipient.address,
+                amount,
+            })
+            .collect(),
+    })
+}
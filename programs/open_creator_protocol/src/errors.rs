@@ -1,5 +1,11 @@
+use anchor_lang::error::AnchorError;
 use anchor_lang::prelude::*;
 
+// the error codes [POLICY_ERROR_CODE_OFFSET, POLICY_ERROR_CODE_OFFSET + POLICY_ERROR_CODE_MAX] are reserved for the
+// errors that the policies attach to their conditions, see ConditionError
+pub const POLICY_ERROR_CODE_OFFSET: u32 = 7000;
+pub const POLICY_ERROR_CODE_MAX: u16 = 999;
+
 #[error_code]
 pub enum OCPErrorCode {
     #[msg("Invalid mint")]
@@ -32,4 +38,47 @@ pub enum OCPErrorCode {
     NotImplemented,
     #[msg("Invalid metadata update_authority")]
     InvalidMetadataUpdateAuthority,
+    #[msg("Invalid royalty recipient")]
+    InvalidRoyaltyRecipient,
+    #[msg("Invalid price mint")]
+    InvalidPriceMint,
+    #[msg("Invalid royalty split")]
+    InvalidRoyaltySplit,
+    #[msg("Invalid royalty exemption")]
+    InvalidRoyaltyExemption,
+    #[msg("Invalid royalty payment memo")]
+    InvalidRoyaltyPaymentMemo,
+    #[msg("Royalty not paid")]
+    RoyaltyNotPaid,
+    #[msg("Invalid rule chunk")]
+    InvalidRuleChunk,
+    #[msg("Invalid list account")]
+    InvalidListAccount,
+    #[msg("Memo required")]
+    MemoRequired,
+    #[msg("Invalid rule event")]
+    InvalidRuleEvent,
+    #[msg("Policy update is timelocked")]
+    PolicyUpdateTimelocked,
+    #[msg("Policy update is not effective yet")]
+    PolicyUpdateNotEffective,
+    #[msg("Invalid program config")]
+    InvalidProgramConfig,
+    #[msg("Policy is frozen")]
+    PolicyFrozen,
+    #[msg("Policy is still in use")]
+    PolicyInUse,
+    #[msg("Policy is paused")]
+    PolicyPaused,
+}
+
+pub fn policy_error(code: u16, message: &str) -> Error {
+    AnchorError {
+        error_name: "PolicyError".to_string(),
+        error_code_number: POLICY_ERROR_CODE_OFFSET + code as u32,
+        error_msg: message.to_string(),
+        error_origin: None,
+        compared_values: None,
+    }
+    .into()
 }
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+// a denial with the error that the policy attached to the failed condition
+#[event]
+pub struct PolicyDenied {
+    pub mint: Pubkey,
+    pub action: String,
+    pub code: u16, // the error code is POLICY_ERROR_CODE_OFFSET + code
+    pub message: String,
+}
+
+// the update of a timelocked policy, effective at the unix timestamp effective_at
+#[event]
+pub struct PolicyUpdateProposed {
+    pub policy: Pubkey,
+    pub effective_at: i64,
+}
+
+#[event]
+pub struct PolicyUpdateApplied {
+    pub policy: Pubkey,
+}
+
+#[event]
+pub struct PolicyUpdateCancelled {
+    pub policy: Pubkey,
+}
+
+#[event]
+pub struct PolicyAuthorityNominated {
+    pub policy: Pubkey,
+    pub pending_authority: Option<Pubkey>, // None when the nomination is withdrawn
+}
+
+#[event]
+pub struct PolicyAuthorityAccepted {
+    pub policy: Pubkey,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct PolicyFrozen {
+    pub policy: Pubkey,
+    pub authority_frozen: bool,
+}
+
+#[event]
+pub struct PolicyPaused {
+    pub policy: Pubkey,
+    pub paused: bool, // false when the pause guardian resumes the policy
+}
@@ -1,6 +1,26 @@
 pub mod policy;
+pub use policy::accept_policy_authority::*;
+pub use policy::apply_policy_update::*;
+pub use policy::cancel_policy_update::*;
+pub use policy::close_policy::*;
+pub use policy::extend_list::*;
+pub use policy::freeze_list::*;
+pub use policy::freeze_policy::*;
+pub use policy::get_royalty_quote::*;
+pub use policy::init_list::*;
 pub use policy::init_policy::*;
+pub use policy::init_rule_chunk::*;
+pub use policy::init_rule_counter::*;
+pub use policy::nominate_policy_authority::*;
+pub use policy::propose_policy_update::*;
+pub use policy::set_policy_paused::*;
+pub use policy::set_policy_roles::*;
+pub use policy::set_program_config::*;
+pub use policy::set_royalty_exemption::*;
+pub use policy::shrink_list::*;
+pub use policy::simulate_policy::*;
 pub use policy::update_policy::*;
+pub use policy::write_rule_chunk::*;
 
 pub mod nft_proxy;
 pub use nft_proxy::approve::*;
@@ -12,5 +32,6 @@ pub use nft_proxy::migrate_to_mpl::*;
 pub use nft_proxy::mint_to::*;
 pub use nft_proxy::revoke::*;
 pub use nft_proxy::transfer::*;
+pub use nft_proxy::transfer_with_payment::*;
 pub use nft_proxy::unlock::*;
 pub use nft_proxy::wrap::*;
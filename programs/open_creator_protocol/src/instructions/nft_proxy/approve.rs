@@ -58,6 +58,7 @@ impl From<&mut ApproveCtx<'_>> for ActionCtx {
             program_ids: vec![],
             last_memo_data: None,
             last_memo_signer: None,
+            merkle_proofs: vec![],
             payer: None,
             from: Some(ctx.from.key().to_string()),
             to: Some(ctx.to.key().to_string()),
@@ -65,6 +66,7 @@ impl From<&mut ApproveCtx<'_>> for ActionCtx {
             metadata: Some(ctx.metadata.clone().into()),
             mint_account: Some(ctx.mint.clone().into()),
             mint_state: ctx.mint_state.clone().into_inner().into(),
+            policy: Some((&**ctx.policy).into()),
         };
         action_ctx
             .parse_instructions(&ctx.instructions)
@@ -75,7 +77,7 @@ impl From<&mut ApproveCtx<'_>> for ActionCtx {
 
 pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, ApproveCtx<'info>>) -> Result<()> {
     let action_ctx: ActionCtx = ctx.accounts.into();
-    ctx.accounts.policy.matches(&action_ctx)?;
+    ctx.accounts.policy.matches(&action_ctx, ctx.remaining_accounts)?;
 
     invoke_signed(
         &create_approve_instruction(
@@ -62,6 +62,7 @@ impl From<&mut BurnCtx<'_>> for ActionCtx {
             program_ids: vec![],
             last_memo_data: None,
             last_memo_signer: None,
+            merkle_proofs: vec![],
             payer: None,
             from: Some(ctx.from.key().to_string()),
             to: None,
@@ -69,6 +70,7 @@ impl From<&mut BurnCtx<'_>> for ActionCtx {
             metadata: Some(ctx.metadata.clone().into()),
             mint_account: Some(ctx.mint.clone().into()),
             mint_state: ctx.mint_state.clone().into_inner().into(),
+            policy: Some((&**ctx.policy).into()),
         };
         action_ctx
             .parse_instructions(&ctx.instructions)
@@ -79,7 +81,8 @@ impl From<&mut BurnCtx<'_>> for ActionCtx {
 
 pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, BurnCtx<'info>>) -> Result<()> {
     let action_ctx: ActionCtx = ctx.accounts.into();
-    ctx.accounts.policy.matches(&action_ctx)?;
+    ctx.accounts.policy.matches(&action_ctx, ctx.remaining_accounts)?;
+    MintCounter::record(&ctx.accounts.policy, false, ctx.remaining_accounts)?; // the mint_state is closed
 
     invoke_signed(
         &create_burn_instruction(&ctx.accounts.mint.key(), &ctx.accounts.from.key(), &ctx.accounts.policy.key(), 1)?,
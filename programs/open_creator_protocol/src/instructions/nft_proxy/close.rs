@@ -59,6 +59,7 @@ impl From<&mut CloseCtx<'_>> for ActionCtx {
             program_ids: vec![],
             last_memo_data: None,
             last_memo_signer: None,
+            merkle_proofs: vec![],
             payer: None,
             from: Some(ctx.from.key().to_string()),
             to: None,
@@ -66,6 +67,7 @@ impl From<&mut CloseCtx<'_>> for ActionCtx {
             metadata: Some(ctx.metadata.clone().into()),
             mint_account: Some(ctx.mint.clone().into()),
             mint_state: ctx.mint_state.clone().into_inner().into(),
+            policy: Some((&**ctx.policy).into()),
         };
         action_ctx
             .parse_instructions(&ctx.instructions)
@@ -76,7 +78,7 @@ impl From<&mut CloseCtx<'_>> for ActionCtx {
 
 pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, CloseCtx<'info>>) -> Result<()> {
     let action_ctx: ActionCtx = ctx.accounts.into();
-    ctx.accounts.policy.matches(&action_ctx)?;
+    ctx.accounts.policy.matches(&action_ctx, ctx.remaining_accounts)?;
 
     invoke_signed(
         &create_close_account_with_destination_instruction(
@@ -57,6 +57,7 @@ impl From<&mut InitAccountCtx<'_>> for ActionCtx {
             program_ids: vec![],
             last_memo_data: None,
             last_memo_signer: None,
+            merkle_proofs: vec![],
             payer: Some(ctx.payer.key().to_string()),
             from: Some(ctx.from.key().to_string()),
             to: None,
@@ -64,6 +65,7 @@ impl From<&mut InitAccountCtx<'_>> for ActionCtx {
             metadata: Some(ctx.metadata.clone().into()),
             mint_account: Some(ctx.mint.clone().into()),
             mint_state: ctx.mint_state.clone().into_inner().into(),
+            policy: Some((&**ctx.policy).into()),
         };
         action_ctx
             .parse_instructions(&ctx.instructions)
@@ -74,7 +76,7 @@ impl From<&mut InitAccountCtx<'_>> for ActionCtx {
 
 pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, InitAccountCtx<'info>>) -> Result<()> {
     let action_ctx: ActionCtx = ctx.accounts.into();
-    ctx.accounts.policy.matches(&action_ctx)?;
+    ctx.accounts.policy.matches(&action_ctx, ctx.remaining_accounts)?;
 
     invoke_signed(
         &create_initialize_account_instruction(
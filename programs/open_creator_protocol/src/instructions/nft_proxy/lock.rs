@@ -47,6 +47,7 @@ impl From<&mut LockCtx<'_>> for ActionCtx {
             program_ids: vec![],
             last_memo_data: None,
             last_memo_signer: None,
+            merkle_proofs: vec![],
             payer: None,
             from: Some(ctx.from.key().to_string()),
             to: Some(ctx.to.key().to_string()),
@@ -54,6 +55,7 @@ impl From<&mut LockCtx<'_>> for ActionCtx {
             metadata: Some(ctx.metadata.clone().into()),
             mint_account: Some(ctx.mint.clone().into()),
             mint_state: ctx.mint_state.clone().into_inner().into(),
+            policy: Some((&**ctx.policy).into()),
         };
         action_ctx
             .parse_instructions(&ctx.instructions)
@@ -64,7 +66,7 @@ impl From<&mut LockCtx<'_>> for ActionCtx {
 
 pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, LockCtx<'info>>) -> Result<()> {
     let action_ctx: ActionCtx = ctx.accounts.into();
-    ctx.accounts.policy.matches(&action_ctx)?;
+    ctx.accounts.policy.matches(&action_ctx, ctx.remaining_accounts)?;
 
     let mint_state = &mut ctx.accounts.mint_state;
     mint_state.locked_by = Some(ctx.accounts.to.key());
@@ -83,6 +83,7 @@ impl From<&mut MigrateToMplCtx<'_>> for ActionCtx {
             program_ids: vec![],
             last_memo_data: None,
             last_memo_signer: None,
+            merkle_proofs: vec![],
             payer: Some(ctx.payer.key().to_string()),
             from: Some(ctx.from.key().to_string()),
             to: None,
@@ -90,6 +91,7 @@ impl From<&mut MigrateToMplCtx<'_>> for ActionCtx {
             metadata: Some(ctx.metadata.clone().into()),
             mint_account: None,
             mint_state: ctx.mint_state.clone().into_inner().into(),
+            policy: Some((&**ctx.policy).into()),
         };
         action_ctx
             .parse_instructions(&ctx.instructions)
@@ -100,7 +102,8 @@ impl From<&mut MigrateToMplCtx<'_>> for ActionCtx {
 
 pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, MigrateToMplCtx<'info>>) -> Result<()> {
     let action_ctx: ActionCtx = ctx.accounts.into();
-    ctx.accounts.policy.matches(&action_ctx)?;
+    ctx.accounts.policy.matches(&action_ctx, ctx.remaining_accounts)?;
+    MintCounter::record(&ctx.accounts.policy, false, ctx.remaining_accounts)?; // the mint_state is closed
 
     invoke_signed(
         &create_migrate_authority_instruction(
@@ -7,6 +7,7 @@ pub mod migrate_to_mpl;
 pub mod mint_to;
 pub mod revoke;
 pub mod transfer;
+pub mod transfer_with_payment;
 pub mod unlock;
 pub mod wrap;
 
@@ -19,5 +20,6 @@ pub use migrate_to_mpl::*;
 pub use mint_to::*;
 pub use revoke::*;
 pub use transfer::*;
+pub use transfer_with_payment::*;
 pub use unlock::*;
 pub use wrap::*;
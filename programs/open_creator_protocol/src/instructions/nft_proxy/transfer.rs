@@ -1,5 +1,6 @@
 use crate::action::*;
 use crate::errors::OCPErrorCode;
+use crate::royalty::{get_paid_lamports, split_royalty_amount, RoyaltyPaymentMemo, ROYALTY_ENFORCEMENT_MODE_INSTRUCTION_INTROSPECTION};
 use crate::state::*;
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::program::invoke_signed;
@@ -48,6 +49,13 @@ pub struct TransferCtx<'info> {
     /// CHECK: This is not dangerous because the ID is checked with instructions sysvar
     #[account(address = sysvar::instructions::id())]
     instructions: UncheckedAccount<'info>,
+    // optional, the royalty of the introspection mode is waived when the from, the to or the calling program is exempted
+    #[account(constraint = royalty_exemption.policy == policy.key() @ OCPErrorCode::InvalidRoyaltyExemption)]
+    royalty_exemption: Option<Box<Account<'info, RoyaltyExemption>>>,
+    // remaining_accounts, in this order:
+    //   - optional, the buyer's (to) pass token account and the pass metadata for DYNAMIC_ROYALTY_KIND_HOLDER_DISCOUNT,
+    //     first since transfer has no royalty recipients in the remaining_accounts, see transfer_with_payment
+    //   - the rule chunks and the list accounts that the rules reference, and the accounts of the rule events
 }
 
 impl From<&mut TransferCtx<'_>> for ActionCtx {
@@ -57,6 +65,7 @@ impl From<&mut TransferCtx<'_>> for ActionCtx {
             program_ids: vec![],
             last_memo_data: None,
             last_memo_signer: None,
+            merkle_proofs: vec![],
             payer: None,
             from: Some(ctx.from.key().to_string()),
             to: Some(ctx.to.key().to_string()),
@@ -64,6 +73,7 @@ impl From<&mut TransferCtx<'_>> for ActionCtx {
             metadata: Some(ctx.metadata.clone().into()),
             mint_account: Some(ctx.mint.clone().into()),
             mint_state: ctx.mint_state.clone().into_inner().into(),
+            policy: Some((&**ctx.policy).into()),
         };
         action_ctx
             .parse_instructions(&ctx.instructions)
@@ -74,7 +84,45 @@ impl From<&mut TransferCtx<'_>> for ActionCtx {
 
 pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, TransferCtx<'info>>) -> Result<()> {
     let action_ctx: ActionCtx = ctx.accounts.into();
-    ctx.accounts.policy.matches(&action_ctx)?;
+    ctx.accounts.policy.matches(&action_ctx, ctx.remaining_accounts)?;
+
+    if ctx.accounts.policy.royalty_enforcement_mode == ROYALTY_ENFORCEMENT_MODE_INSTRUCTION_INTROSPECTION {
+        // the price is declared in a memo, and the royalty must be paid by the from or the to directly before this instruction
+        let memo = RoyaltyPaymentMemo::parse(action_ctx.last_memo_data.as_ref())?;
+        let is_exempted = match &ctx.accounts.royalty_exemption {
+            Some(royalty_exemption) => royalty_exemption.is_exempted(
+                Some(&ctx.accounts.from_account.owner),
+                Some(&ctx.accounts.to.key()),
+                get_calling_program(&ctx.accounts.instructions)?.as_ref(),
+            ),
+            None => false,
+        };
+        let is_pass_holder = ctx.accounts.policy.is_pass_holder(&ctx.accounts.to.key(), ctx.remaining_accounts)?;
+        let royalty_bp = match is_exempted {
+            true => 0,
+            false => ctx.accounts.policy.get_royalty_bp(
+                memo.price,
+                ctx.accounts.mint_state.get_holding_duration(),
+                is_pass_holder,
+                ctx.accounts.metadata.seller_fee_basis_points,
+            ),
+        };
+        let royalty_amount = match is_exempted {
+            true => 0,
+            false => ctx.accounts.policy.get_royalty_amount(memo.price, royalty_bp)?,
+        };
+        let recipients = ctx.accounts.policy.get_royalty_recipients(&ctx.accounts.metadata);
+        let amounts = split_royalty_amount(royalty_amount, &recipients.iter().map(|x| x.share).collect::<Vec<u8>>())?;
+        let paid = get_paid_lamports(
+            &ctx.accounts.instructions,
+            &[ctx.accounts.from.key(), ctx.accounts.to.key()],
+            &recipients.iter().map(|x| x.address).collect::<Vec<Pubkey>>(),
+        )?;
+        if amounts.iter().zip(paid).any(|(amount, paid)| paid < *amount) {
+            msg!("price: {}, royalty_bp: {}, royalty_amount: {}", memo.price, royalty_bp, royalty_amount);
+            return Err(OCPErrorCode::RoyaltyNotPaid.into());
+        }
+    }
 
     invoke_signed(
         &create_transfer_with_delegate_instruction(
@@ -0,0 +1,239 @@
+use crate::action::*;
+use crate::errors::OCPErrorCode;
+use crate::merkle::MerkleProof;
+use crate::royalty::{check_payment_mint, check_recipient_token_account, split_royalty_amount};
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_lang::solana_program::sysvar;
+use anchor_lang::system_program;
+use anchor_spl::metadata::MetadataAccount;
+use anchor_spl::token;
+use anchor_spl::token::Mint;
+use anchor_spl::token::Token;
+use anchor_spl::token::TokenAccount;
+use community_managed_token::instruction::create_transfer_with_delegate_instruction;
+
+#[derive(Default, AnchorSerialize, AnchorDeserialize)]
+pub struct TransferWithPaymentArg {
+    pub price: u64,
+    pub merkle_proofs: Vec<MerkleProof>, // for the merkle operators of the policy, in addition to the ones in the memo
+}
+
+#[derive(Accounts)]
+pub struct TransferWithPaymentCtx<'info> {
+    policy: Box<Account<'info, Policy>>,
+    /// CHECK: Checked in cpi
+    freeze_authority: UncheckedAccount<'info>,
+    #[account(
+        constraint = mint_state.mint == mint.key() @ OCPErrorCode::InvalidMint,
+        constraint = mint.key() == from_account.mint @ OCPErrorCode::InvalidMint,
+        constraint = mint_state.locked_by.is_none() @ OCPErrorCode::MintStateLocked,
+        constraint = mint.freeze_authority == COption::Some(freeze_authority.key()) @ OCPErrorCode::InvalidPolicyMintAssociation,
+        constraint = mint.mint_authority == COption::Some(freeze_authority.key()) @ OCPErrorCode::InvalidPolicyMintAssociation,
+        constraint = mint_state.policy == policy.key() @ OCPErrorCode::InvalidPolicyMintAssociation,
+        constraint = policy.get_freeze_authority(policy.key()) == freeze_authority.key() @ OCPErrorCode::InvalidPolicyMintAssociation,
+    )]
+    mint: Box<Account<'info, Mint>>,
+    #[account(
+        seeds = [b"metadata", anchor_spl::metadata::Metadata::id().as_ref(), mint.key().as_ref()],
+        seeds::program = anchor_spl::metadata::Metadata::id(),
+        bump,
+    )]
+    metadata: Box<Account<'info, MetadataAccount>>,
+    #[account(mut)]
+    mint_state: Box<Account<'info, MintState>>,
+    #[account(
+        init,
+        payer = payer,
+        space = RoyaltyReceipt::LEN,
+        seeds = [RoyaltyReceipt::SEED.as_bytes(), mint.key().as_ref(), mint_state.sale_count.to_le_bytes().as_ref()],
+        bump,
+    )]
+    royalty_receipt: Box<Account<'info, RoyaltyReceipt>>,
+    from: Signer<'info>, // either the owner of the token or the delegate of the token
+    #[account(mut)]
+    from_account: Box<Account<'info, TokenAccount>>,
+    /// CHECK: checked in cpi
+    to: UncheckedAccount<'info>,
+    #[account(mut, constraint = to_account.owner == to.key() @ OCPErrorCode::InvalidTokenAccount)]
+    to_account: Box<Account<'info, TokenAccount>>,
+    #[account(mut)]
+    payer: Signer<'info>, // the buyer who pays the royalty
+    // only required when the policy's dynamic royalty sets a price_mint
+    payment_mint: Option<Box<Account<'info, Mint>>>,
+    #[account(
+        mut,
+        constraint = payer_payment_account.owner == payer.key() @ OCPErrorCode::InvalidTokenAccount,
+    )]
+    payer_payment_account: Option<Box<Account<'info, TokenAccount>>>,
+    // optional, the royalty is waived when the from, the to or the calling program is exempted
+    #[account(constraint = royalty_exemption.policy == policy.key() @ OCPErrorCode::InvalidRoyaltyExemption)]
+    royalty_exemption: Option<Box<Account<'info, RoyaltyExemption>>>,
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+    /// CHECK: checked in cpi
+    #[account(address = community_managed_token::id())]
+    cmt_program: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because the ID is checked with instructions sysvar
+    #[account(address = sysvar::instructions::id())]
+    instructions: UncheckedAccount<'info>,
+    // remaining_accounts, in this order:
+    //   - the royalty recipients (or their payment_mint token accounts), in the same order as policy.royalty_split,
+    //     or metadata.creators when the policy doesn't override the split
+    //   - optional, the buyer's (to) pass token account and the pass metadata for DYNAMIC_ROYALTY_KIND_HOLDER_DISCOUNT,
+    //     right after the royalty recipients, see transfer
+    //   - the rule chunks and the list accounts that the rules reference, and the accounts of the rule events
+}
+
+impl From<&mut TransferWithPaymentCtx<'_>> for ActionCtx {
+    fn from(ctx: &mut TransferWithPaymentCtx) -> Self {
+        let mut action_ctx = ActionCtx {
+            action: "transfer".to_string(), // a sale is still a transfer for the policy
+            program_ids: vec![],
+            last_memo_data: None,
+            last_memo_signer: None,
+            merkle_proofs: vec![],
+            payer: Some(ctx.payer.key().to_string()),
+            from: Some(ctx.from.key().to_string()),
+            to: Some(ctx.to.key().to_string()),
+            mint: ctx.mint.key().to_string(),
+            metadata: Some(ctx.metadata.clone().into()),
+            mint_account: Some(ctx.mint.clone().into()),
+            mint_state: ctx.mint_state.clone().into_inner().into(),
+            policy: Some((&**ctx.policy).into()),
+        };
+        action_ctx
+            .parse_instructions(&ctx.instructions)
+            .expect("failed to parse sysvar instructions");
+        action_ctx
+    }
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, TransferWithPaymentCtx<'info>>, arg: TransferWithPaymentArg) -> Result<()> {
+    let mut action_ctx: ActionCtx = ctx.accounts.into();
+    action_ctx.merkle_proofs.extend(arg.merkle_proofs);
+    ctx.accounts.policy.matches(&action_ctx, ctx.remaining_accounts)?;
+
+    let recipients = ctx.accounts.policy.get_royalty_recipients(&ctx.accounts.metadata);
+    if ctx.remaining_accounts.len() < recipients.len() {
+        return Err(OCPErrorCode::AccountNotFound.into());
+    }
+    let is_pass_holder = ctx
+        .accounts
+        .policy
+        .is_pass_holder(&ctx.accounts.to.key(), &ctx.remaining_accounts[recipients.len()..])?;
+
+    let is_exempted = match &ctx.accounts.royalty_exemption {
+        Some(royalty_exemption) => royalty_exemption.is_exempted(
+            Some(&ctx.accounts.from_account.owner),
+            Some(&ctx.accounts.to.key()),
+            get_calling_program(&ctx.accounts.instructions)?.as_ref(),
+        ),
+        None => false,
+    };
+    let royalty_bp = match is_exempted {
+        true => 0,
+        false => ctx.accounts.policy.get_royalty_bp(
+            arg.price,
+            ctx.accounts.mint_state.get_holding_duration(),
+            is_pass_holder,
+            ctx.accounts.metadata.seller_fee_basis_points,
+        ),
+    };
+    let royalty_amount = match is_exempted {
+        true => 0,
+        false => ctx.accounts.policy.get_royalty_amount(arg.price, royalty_bp)?,
+    };
+    msg!("royalty_bp: {}, royalty_amount: {}", royalty_bp, royalty_amount);
+
+    let amounts = split_royalty_amount(royalty_amount, &recipients.iter().map(|x| x.share).collect::<Vec<u8>>())?;
+    let price_mint = ctx.accounts.policy.get_price_mint();
+    check_payment_mint(price_mint, ctx.accounts.payment_mint.as_ref().map(|x| x.key()))?;
+    match price_mint {
+        None => {
+            for ((recipient, amount), recipient_account) in recipients.iter().zip(amounts).zip(ctx.remaining_accounts.iter()) {
+                if recipient_account.key() != recipient.address {
+                    return Err(OCPErrorCode::InvalidRoyaltyRecipient.into());
+                }
+                if amount == 0 {
+                    continue;
+                }
+                system_program::transfer(
+                    CpiContext::new(
+                        ctx.accounts.system_program.to_account_info(),
+                        system_program::Transfer {
+                            from: ctx.accounts.payer.to_account_info(),
+                            to: recipient_account.to_account_info(),
+                        },
+                    ),
+                    amount,
+                )?;
+            }
+        }
+        Some(price_mint) => {
+            let payer_payment_account = ctx.accounts.payer_payment_account.as_ref().ok_or(OCPErrorCode::AccountNotFound)?;
+            if payer_payment_account.mint != price_mint {
+                return Err(OCPErrorCode::InvalidTokenAccount.into());
+            }
+            for ((recipient, amount), recipient_account) in recipients.iter().zip(amounts).zip(ctx.remaining_accounts.iter()) {
+                check_recipient_token_account(recipient_account, &recipient.address, &price_mint)?;
+                if amount == 0 {
+                    continue;
+                }
+                token::transfer(
+                    CpiContext::new(
+                        ctx.accounts.token_program.to_account_info(),
+                        token::Transfer {
+                            from: payer_payment_account.to_account_info(),
+                            to: recipient_account.to_account_info(),
+                            authority: ctx.accounts.payer.to_account_info(),
+                        },
+                    ),
+                    amount,
+                )?;
+            }
+        }
+    }
+
+    invoke_signed(
+        &create_transfer_with_delegate_instruction(
+            &ctx.accounts.from_account.owner,
+            &ctx.accounts.to_account.owner,
+            &ctx.accounts.from.key(), // from can be either the delegate or the owner of the from_account
+            &ctx.accounts.mint.key(),
+            &ctx.accounts.policy.key(),
+            1,
+        )?,
+        &[
+            ctx.accounts.from_account.to_account_info(),
+            ctx.accounts.to_account.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.from.to_account_info(),
+            ctx.accounts.policy.to_account_info(),
+            ctx.accounts.freeze_authority.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.cmt_program.to_account_info(),
+        ],
+        &[&ctx.accounts.policy.signer_seeds()],
+    )?;
+
+    let royalty_receipt = &mut ctx.accounts.royalty_receipt;
+    royalty_receipt.version = 0;
+    royalty_receipt.bump = [ctx.bumps.royalty_receipt];
+    royalty_receipt.mint = ctx.accounts.mint.key();
+    royalty_receipt.sequence = ctx.accounts.mint_state.sale_count;
+    royalty_receipt.payer = ctx.accounts.payer.key();
+    royalty_receipt.price = arg.price;
+    royalty_receipt.price_mint = price_mint;
+    royalty_receipt.royalty_bp = royalty_bp;
+    royalty_receipt.royalty_amount = royalty_amount;
+    royalty_receipt.paid_at = Clock::get()?.unix_timestamp;
+
+    let mint_state = &mut ctx.accounts.mint_state;
+    mint_state.sale_count = mint_state.sale_count.checked_add(1).ok_or(OCPErrorCode::NumericalOverflow)?;
+    mint_state.record_transfer();
+
+    Ok(())
+}
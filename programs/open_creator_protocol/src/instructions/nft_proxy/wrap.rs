@@ -59,6 +59,7 @@ impl From<&mut WrapCtx<'_>> for ActionCtx {
             program_ids: vec![],
             last_memo_data: None,
             last_memo_signer: None,
+            merkle_proofs: vec![],
             payer: None,
             from: Some(ctx.from.key().to_string()),
             to: None,
@@ -66,6 +67,7 @@ impl From<&mut WrapCtx<'_>> for ActionCtx {
             metadata: Some(ctx.metadata.clone().into()),
             mint_account: None,
             mint_state: ctx.mint_state.clone().into_inner().into(),
+            policy: Some((&**ctx.policy).into()),
         };
         action_ctx
             .parse_instructions(&ctx.instructions)
@@ -77,7 +79,7 @@ impl From<&mut WrapCtx<'_>> for ActionCtx {
 pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, WrapCtx<'info>>) -> Result<()> {
     let action_ctx: ActionCtx = ctx.accounts.into();
     let policy = &ctx.accounts.policy;
-    ctx.accounts.policy.matches(&action_ctx)?;
+    ctx.accounts.policy.matches(&action_ctx, ctx.remaining_accounts)?;
 
     let mint_state = &mut ctx.accounts.mint_state;
     mint_state.bump = [ctx.bumps.mint_state];
@@ -109,5 +111,5 @@ pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, WrapCtx<'info>>) -> Result
         Some(policy.get_freeze_authority(policy.key())),
     )?;
 
-    Ok(())
+    MintCounter::record(&ctx.accounts.policy, true, ctx.remaining_accounts)
 }
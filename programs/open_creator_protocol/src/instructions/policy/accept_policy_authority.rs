@@ -0,0 +1,26 @@
+use crate::errors::OCPErrorCode;
+use crate::events::PolicyAuthorityAccepted;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AcceptPolicyAuthorityCtx<'info> {
+    #[account(
+        mut,
+        constraint = policy.pending_authority == Some(pending_authority.key()) @ OCPErrorCode::InvalidAuthority,
+        constraint = !policy.authority_frozen @ OCPErrorCode::PolicyFrozen,
+    )]
+    policy: Box<Account<'info, Policy>>,
+    pending_authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<AcceptPolicyAuthorityCtx>) -> Result<()> {
+    let policy = &mut ctx.accounts.policy;
+    policy.authority = ctx.accounts.pending_authority.key();
+    policy.pending_authority = None;
+    emit!(PolicyAuthorityAccepted {
+        policy: policy.key(),
+        authority: policy.authority,
+    });
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use crate::errors::OCPErrorCode;
+use crate::events::PolicyUpdateApplied;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ApplyPolicyUpdateCtx<'info> {
+    #[account(
+        mut,
+        realloc = Policy::LEN,
+        realloc::payer = authority,
+        realloc::zero = false,
+    )]
+    policy: Box<Account<'info, Policy>>,
+    #[account(
+        mut,
+        close = authority,
+        seeds = [PendingPolicyUpdate::SEED.as_bytes(), policy.key().as_ref()],
+        bump = pending_policy_update.bump[0],
+    )]
+    pending_policy_update: Box<Account<'info, PendingPolicyUpdate>>,
+    #[account(
+        mut,
+        constraint = policy.is_update_admin(&authority.key(), program_config.as_deref()) @ OCPErrorCode::InvalidAuthority,
+    )]
+    authority: Signer<'info>,
+    system_program: Program<'info, System>,
+    #[account(seeds = [ProgramConfig::SEED.as_bytes()], bump = program_config.bump[0])]
+    program_config: Option<Account<'info, ProgramConfig>>, // for the managed authorities
+}
+
+pub fn handler(ctx: Context<ApplyPolicyUpdateCtx>) -> Result<()> {
+    if Clock::get()?.unix_timestamp < ctx.accounts.pending_policy_update.effective_at {
+        return Err(OCPErrorCode::PolicyUpdateNotEffective.into());
+    }
+    let update = ctx.accounts.pending_policy_update.update.clone();
+    let rule_chunk_count = ctx.accounts.policy.rule_chunk_count;
+    ctx.accounts.policy.update(update)?;
+    ctx.accounts.policy.check_new_rule_chunks(rule_chunk_count, ctx.remaining_accounts)?; // the newly referenced rule chunks
+    ctx.accounts.policy.remove_account(); // the pending_policy_update is closed
+
+    emit!(PolicyUpdateApplied {
+        policy: ctx.accounts.policy.key(),
+    });
+    Ok(())
+}
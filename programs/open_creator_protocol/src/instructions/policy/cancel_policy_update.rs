@@ -0,0 +1,42 @@
+use crate::errors::OCPErrorCode;
+use crate::events::PolicyUpdateCancelled;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CancelPolicyUpdateCtx<'info> {
+    #[account(
+        mut,
+        realloc = Policy::LEN, // policies created before the account grew are resized to count the accounts
+        realloc::payer = authority,
+        realloc::zero = false,
+    )]
+    policy: Box<Account<'info, Policy>>,
+    #[account(
+        mut,
+        close = authority,
+        seeds = [PendingPolicyUpdate::SEED.as_bytes(), policy.key().as_ref()],
+        bump = pending_policy_update.bump[0],
+    )]
+    pending_policy_update: Box<Account<'info, PendingPolicyUpdate>>,
+    #[account(
+        mut,
+        constraint = (
+            policy.is_authority(&authority.key(), program_config.as_deref()) ||
+            policy.is_update_admin(&authority.key(), program_config.as_deref()) ||
+            policy.is_pause_guardian(&authority.key(), program_config.as_deref())
+        ) @ OCPErrorCode::InvalidAuthority,
+    )]
+    authority: Signer<'info>,
+    system_program: Program<'info, System>,
+    #[account(seeds = [ProgramConfig::SEED.as_bytes()], bump = program_config.bump[0])]
+    program_config: Option<Account<'info, ProgramConfig>>, // for the managed authorities
+}
+
+pub fn handler(ctx: Context<CancelPolicyUpdateCtx>) -> Result<()> {
+    ctx.accounts.policy.remove_account(); // the pending_policy_update is closed
+    emit!(PolicyUpdateCancelled {
+        policy: ctx.accounts.policy.key(),
+    });
+    Ok(())
+}
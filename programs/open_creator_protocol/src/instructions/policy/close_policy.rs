@@ -0,0 +1,72 @@
+use crate::errors::OCPErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ClosePolicyCtx<'info> {
+    // the version 0 policies never counted their mints and accounts, and there is no migration for them, since the
+    // mint states of a policy can't be enumerated on-chain to start the count
+    #[account(mut, close = authority, constraint = policy.is_counted() @ OCPErrorCode::PolicyInUse)]
+    policy: Box<Account<'info, Policy>>,
+    #[account(
+        mut,
+        constraint = policy.is_authority(&authority.key(), program_config.as_deref()) @ OCPErrorCode::InvalidAuthority,
+    )]
+    authority: Signer<'info>,
+    // for the managed authorities
+    #[account(seeds = [ProgramConfig::SEED.as_bytes()], bump = program_config.bump[0])]
+    program_config: Option<Account<'info, ProgramConfig>>,
+    // remaining_accounts:
+    //   - every rule chunk, rule counter, royalty exemption, pending policy update and the mint counter of the policy
+}
+
+// the rent of the policy and its accounts goes back to the authority. The list accounts are not owned by a policy,
+// so they are left as is
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, ClosePolicyCtx<'info>>) -> Result<()> {
+    let policy = ctx.accounts.policy.key();
+    let authority = ctx.accounts.authority.to_account_info();
+    for account in ctx.remaining_accounts.iter().filter(|x| x.owner == &crate::id() && x.is_writable) {
+        if !is_policy_account(&policy, account)? {
+            continue;
+        }
+        let lamports = authority
+            .lamports()
+            .checked_add(account.lamports())
+            .ok_or(OCPErrorCode::NumericalOverflow)?;
+        **authority.try_borrow_mut_lamports()? = lamports;
+        **account.try_borrow_mut_lamports()? = 0;
+        account.assign(&System::id());
+        account.realloc(0, false)?;
+        ctx.accounts.policy.remove_account();
+    }
+    if !ctx.accounts.policy.is_closable() {
+        msg!("{} accounts of the policy are not closed", ctx.accounts.policy.account_count);
+        return Err(OCPErrorCode::PolicyInUse.into());
+    }
+    Ok(())
+}
+
+// whether the account is a PDA of the policy that is closed with it. A mint counter can only be closed at 0
+fn is_policy_account(policy: &Pubkey, account: &AccountInfo) -> Result<bool> {
+    let data = account.try_borrow_data()?;
+    if let Ok(rule_chunk) = RuleChunk::try_deserialize(&mut &data[..]) {
+        return Ok(rule_chunk.policy == *policy);
+    }
+    if let Ok(rule_counter) = RuleCounter::try_deserialize(&mut &data[..]) {
+        return Ok(rule_counter.policy == *policy);
+    }
+    if let Ok(royalty_exemption) = RoyaltyExemption::try_deserialize(&mut &data[..]) {
+        return Ok(royalty_exemption.policy == *policy);
+    }
+    if let Ok(pending_policy_update) = PendingPolicyUpdate::try_deserialize(&mut &data[..]) {
+        return Ok(pending_policy_update.policy == *policy);
+    }
+    if let Ok(mint_counter) = MintCounter::try_deserialize(&mut &data[..]) {
+        if mint_counter.policy == *policy && mint_counter.count > 0 {
+            msg!("{} mint states still reference the policy", mint_counter.count);
+            return Err(OCPErrorCode::PolicyInUse.into());
+        }
+        return Ok(mint_counter.policy == *policy);
+    }
+    Ok(false)
+}
@@ -0,0 +1,31 @@
+use crate::errors::OCPErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Default, AnchorSerialize, AnchorDeserialize)]
+pub struct ExtendListArg {
+    pub keys: Vec<Pubkey>, // must not be in the list yet
+}
+
+#[derive(Accounts)]
+#[instruction(arg: ExtendListArg)]
+pub struct ExtendListCtx<'info> {
+    #[account(
+        mut,
+        constraint = list.authority == authority.key() @ OCPErrorCode::InvalidAuthority,
+        realloc = ListAccount::space(list.keys.len() + arg.keys.len()),
+        realloc::payer = authority,
+        realloc::zero = false,
+    )]
+    list: Box<Account<'info, ListAccount>>,
+    #[account(mut)]
+    authority: Signer<'info>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ExtendListCtx>, arg: ExtendListArg) -> Result<()> {
+    let list = &mut ctx.accounts.list;
+    list.keys.extend(arg.keys);
+    list.keys.sort();
+    list.valid() // fails on the duplicated keys
+}
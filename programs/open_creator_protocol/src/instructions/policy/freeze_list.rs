@@ -0,0 +1,19 @@
+use crate::errors::OCPErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct FreezeListCtx<'info> {
+    #[account(
+        mut,
+        constraint = list.authority == authority.key() @ OCPErrorCode::InvalidAuthority,
+    )]
+    list: Box<Account<'info, ListAccount>>,
+    authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<FreezeListCtx>) -> Result<()> {
+    // nobody can sign for the default pubkey, so the list can't be extended or shrunk anymore
+    ctx.accounts.list.authority = Pubkey::default();
+    Ok(())
+}
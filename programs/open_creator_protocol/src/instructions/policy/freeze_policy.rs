@@ -0,0 +1,48 @@
+use crate::errors::OCPErrorCode;
+use crate::events::PolicyFrozen;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Default, AnchorSerialize, AnchorDeserialize)]
+pub struct FreezePolicyArg {
+    pub freeze_authority: bool, // the authority can be frozen later with another freeze_policy
+}
+
+#[derive(Accounts)]
+pub struct FreezePolicyCtx<'info> {
+    #[account(
+        mut,
+        constraint = !policy.paused @ OCPErrorCode::PolicyPaused, // a frozen policy couldn't be resumed
+        realloc = Policy::LEN, // policies created before the account grew are resized on freeze
+        realloc::payer = authority,
+        realloc::zero = false,
+    )]
+    policy: Box<Account<'info, Policy>>,
+    // only the policy's own authority, a managed authority can't give up the policy for it
+    #[account(
+        mut,
+        constraint = authority.key() == policy.authority @ OCPErrorCode::InvalidAuthority,
+    )]
+    authority: Signer<'info>,
+    system_program: Program<'info, System>,
+    // remaining_accounts: the rule chunks and the list accounts that the rules reference, the lists must be frozen
+}
+
+// permanent, there is no unfreeze. The rules and the royalties of a frozen policy can't be updated, even by the
+// managed authorities, and the authority of a policy with authority_frozen can't be transferred
+pub fn handler(ctx: Context<FreezePolicyCtx>, arg: FreezePolicyArg) -> Result<()> {
+    let policy = &mut ctx.accounts.policy;
+    if !policy.frozen {
+        policy.check_frozen_lists(ctx.remaining_accounts)?;
+    }
+    policy.frozen = true;
+    if arg.freeze_authority {
+        policy.authority_frozen = true;
+        policy.pending_authority = None;
+    }
+    emit!(PolicyFrozen {
+        policy: policy.key(),
+        authority_frozen: policy.authority_frozen,
+    });
+    Ok(())
+}
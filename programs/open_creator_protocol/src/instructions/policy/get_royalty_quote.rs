@@ -0,0 +1,86 @@
+use crate::errors::OCPErrorCode;
+use crate::royalty::split_royalty_amount;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::metadata::MetadataAccount;
+
+#[derive(Default, AnchorSerialize, AnchorDeserialize)]
+pub struct GetRoyaltyQuoteArg {
+    pub price: u64,
+    pub from: Option<Pubkey>, // the seller, only used for the royalty exemption
+    pub to: Option<Pubkey>,   // the buyer, used for the royalty exemption and the holder discount
+    pub calling_program: Option<Pubkey>,
+}
+
+#[derive(Default, AnchorSerialize, AnchorDeserialize)]
+pub struct RoyaltyQuoteRecipient {
+    pub address: Pubkey,
+    pub amount: u64,
+}
+
+#[derive(Default, AnchorSerialize, AnchorDeserialize)]
+pub struct RoyaltyQuote {
+    pub price_mint: Option<Pubkey>, // None for SOL
+    pub royalty_bp: u16,
+    pub royalty_amount: u64,
+    pub recipients: Vec<RoyaltyQuoteRecipient>,
+}
+
+#[derive(Accounts)]
+pub struct GetRoyaltyQuoteCtx<'info> {
+    policy: Box<Account<'info, Policy>>,
+    metadata: Box<Account<'info, MetadataAccount>>,
+    // optional, used for the holding time based royalty. Without it, the holding duration is seen as 0
+    #[account(
+        constraint = mint_state.mint == metadata.mint @ OCPErrorCode::InvalidMint,
+        constraint = mint_state.policy == policy.key() @ OCPErrorCode::InvalidPolicyMintAssociation,
+    )]
+    mint_state: Option<Box<Account<'info, MintState>>>,
+    #[account(constraint = royalty_exemption.policy == policy.key() @ OCPErrorCode::InvalidRoyaltyExemption)]
+    royalty_exemption: Option<Box<Account<'info, RoyaltyExemption>>>,
+    // remaining_accounts: optional, the buyer's (arg.to) pass token account and the pass metadata for
+    // DYNAMIC_ROYALTY_KIND_HOLDER_DISCOUNT, first as in transfer
+}
+
+// read-only, the quote is written with set_return_data so that it can be used from cpi or simulation
+pub fn handler(ctx: Context<GetRoyaltyQuoteCtx>, arg: GetRoyaltyQuoteArg) -> Result<RoyaltyQuote> {
+    let policy = &ctx.accounts.policy;
+    let metadata = &ctx.accounts.metadata;
+
+    let holding_duration = match &ctx.accounts.mint_state {
+        Some(mint_state) => mint_state.get_holding_duration(),
+        None => 0,
+    };
+    let is_pass_holder = match &arg.to {
+        Some(to) => policy.is_pass_holder(to, ctx.remaining_accounts)?,
+        None => false,
+    };
+    let is_exempted = match &ctx.accounts.royalty_exemption {
+        Some(royalty_exemption) => royalty_exemption.is_exempted(arg.from.as_ref(), arg.to.as_ref(), arg.calling_program.as_ref()),
+        None => false,
+    };
+    let royalty_bp = match is_exempted {
+        true => 0,
+        false => policy.get_royalty_bp(arg.price, holding_duration, is_pass_holder, metadata.seller_fee_basis_points),
+    };
+    let royalty_amount = match is_exempted {
+        true => 0,
+        false => policy.get_royalty_amount(arg.price, royalty_bp)?,
+    };
+    let recipients = policy.get_royalty_recipients(metadata);
+    let amounts = split_royalty_amount(royalty_amount, &recipients.iter().map(|x| x.share).collect::<Vec<u8>>())?;
+
+    Ok(RoyaltyQuote {
+        price_mint: policy.get_price_mint(),
+        royalty_bp,
+        royalty_amount,
+        recipients: recipients
+            .iter()
+            .zip(amounts)
+            .map(|(recipient, amount)| RoyaltyQuoteRecipient {
+                address: recipient.address,
+                amount,
+            })
+            .collect(),
+    })
+}
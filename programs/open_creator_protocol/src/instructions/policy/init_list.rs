@@ -0,0 +1,29 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct InitListCtx<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = ListAccount::space(0),
+        seeds = [ListAccount::SEED.as_bytes(), uuid.key().as_ref()],
+        bump,
+    )]
+    list: Box<Account<'info, ListAccount>>,
+    /// CHECK: only used as a random seed
+    uuid: UncheckedAccount<'info>,
+    #[account(mut)]
+    authority: Signer<'info>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitListCtx>) -> Result<()> {
+    let list = &mut ctx.accounts.list;
+    list.version = 0;
+    list.bump = [ctx.bumps.list];
+    list.uuid = ctx.accounts.uuid.key();
+    list.authority = ctx.accounts.authority.key();
+    list.keys = vec![];
+    Ok(())
+}
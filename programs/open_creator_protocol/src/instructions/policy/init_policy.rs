@@ -1,10 +1,34 @@
-use crate::{royalty::DynamicRoyalty, state::*};
+use crate::{
+    royalty::{DynamicRoyalty, RoyaltyRecipient},
+    state::*,
+};
 use anchor_lang::prelude::*;
 
 #[derive(Default, AnchorSerialize, AnchorDeserialize)]
 pub struct InitPolicyArg {
     pub json_rule: Option<String>,
     pub dynamic_royalty: Option<DynamicRoyalty>,
+    pub royalty_split: Option<Vec<RoyaltyRecipient>>,
+    pub royalty_enforcement_mode: u8,
+    pub rule_chunk_count: u8,
+    pub action_rules: Vec<ActionRuleArg>,
+    pub update_delay: i64, // 0 for no timelock
+}
+
+#[derive(Default, AnchorSerialize, AnchorDeserialize)]
+pub struct ActionRuleArg {
+    pub action: String,
+    pub json_rule: String,
+}
+
+impl From<ActionRuleArg> for ActionRule {
+    fn from(arg: ActionRuleArg) -> Self {
+        Self {
+            action: arg.action,
+            json_rule: arg.json_rule,
+            compiled_rule: None, // compiled by Policy::compile_rule
+        }
+    }
 }
 
 #[derive(Accounts)]
@@ -18,6 +42,14 @@ pub struct InitPolicyCtx<'info> {
         bump,
     )]
     policy: Box<Account<'info, Policy>>,
+    #[account(
+        init,
+        payer = authority,
+        space = MintCounter::LEN,
+        seeds = [MintCounter::SEED.as_bytes(), policy.key().as_ref()],
+        bump,
+    )]
+    mint_counter: Box<Account<'info, MintCounter>>, // wrap, burn and migrate_to_mpl count the mints of the policy in it
     /// CHECK: only used as a random seed
     uuid: UncheckedAccount<'info>,
     #[account(mut)]
@@ -27,11 +59,24 @@ pub struct InitPolicyCtx<'info> {
 
 pub fn handler(ctx: Context<InitPolicyCtx>, arg: InitPolicyArg) -> Result<()> {
     let policy = &mut ctx.accounts.policy;
-    policy.version = 0;
+    policy.version = Policy::VERSION;
     policy.bump = [ctx.bumps.policy];
     policy.uuid = ctx.accounts.uuid.key();
     policy.authority = ctx.accounts.authority.key();
     policy.json_rule = arg.json_rule;
     policy.dynamic_royalty = arg.dynamic_royalty;
-    policy.valid()
+    policy.royalty_split = arg.royalty_split;
+    policy.royalty_enforcement_mode = arg.royalty_enforcement_mode;
+    policy.rule_chunk_count = arg.rule_chunk_count;
+    policy.action_rules = arg.action_rules.into_iter().map(ActionRule::from).collect();
+    policy.update_delay = arg.update_delay;
+    policy.compile_rule();
+    policy.valid()?;
+
+    let mint_counter = &mut ctx.accounts.mint_counter;
+    mint_counter.version = 0;
+    mint_counter.bump = [ctx.bumps.mint_counter];
+    mint_counter.policy = policy.key();
+    mint_counter.count = 0;
+    policy.add_account()
 }
@@ -0,0 +1,48 @@
+use crate::errors::OCPErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Default, AnchorSerialize, AnchorDeserialize)]
+pub struct InitRuleChunkArg {
+    pub index: u8,
+}
+
+#[derive(Accounts)]
+#[instruction(arg: InitRuleChunkArg)]
+pub struct InitRuleChunkCtx<'info> {
+    #[account(
+        mut,
+        constraint = !policy.frozen @ OCPErrorCode::PolicyFrozen,
+        constraint = policy.update_delay == 0 @ OCPErrorCode::PolicyUpdateTimelocked, // see propose_policy_update
+        realloc = Policy::LEN, // policies created before the account grew are resized to count the accounts
+        realloc::payer = authority,
+        realloc::zero = false,
+    )]
+    policy: Box<Account<'info, Policy>>,
+    #[account(
+        init,
+        payer = authority,
+        space = RuleChunk::space(0),
+        seeds = [RuleChunk::SEED.as_bytes(), policy.key().as_ref(), &[arg.index]],
+        bump,
+    )]
+    rule_chunk: Box<Account<'info, RuleChunk>>,
+    #[account(
+        mut,
+        constraint = policy.is_rule_admin(&authority.key(), program_config.as_deref()) @ OCPErrorCode::InvalidAuthority,
+    )]
+    authority: Signer<'info>,
+    system_program: Program<'info, System>,
+    #[account(seeds = [ProgramConfig::SEED.as_bytes()], bump = program_config.bump[0])]
+    program_config: Option<Account<'info, ProgramConfig>>, // for the managed authorities
+}
+
+pub fn handler(ctx: Context<InitRuleChunkCtx>, arg: InitRuleChunkArg) -> Result<()> {
+    let rule_chunk = &mut ctx.accounts.rule_chunk;
+    rule_chunk.version = 0;
+    rule_chunk.bump = [ctx.bumps.rule_chunk];
+    rule_chunk.policy = ctx.accounts.policy.key();
+    rule_chunk.index = arg.index;
+    rule_chunk.json_rule = vec![];
+    ctx.accounts.policy.add_account()
+}
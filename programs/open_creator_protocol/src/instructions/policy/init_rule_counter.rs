@@ -0,0 +1,46 @@
+use crate::errors::OCPErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Default, AnchorSerialize, AnchorDeserialize)]
+pub struct InitRuleCounterArg {
+    pub index: u8,
+}
+
+#[derive(Accounts)]
+#[instruction(arg: InitRuleCounterArg)]
+pub struct InitRuleCounterCtx<'info> {
+    #[account(
+        mut,
+        realloc = Policy::LEN, // policies created before the account grew are resized to count the accounts
+        realloc::payer = authority,
+        realloc::zero = false,
+    )]
+    policy: Box<Account<'info, Policy>>,
+    #[account(
+        init,
+        payer = authority,
+        space = RuleCounter::LEN,
+        seeds = [RuleCounter::SEED.as_bytes(), policy.key().as_ref(), &[arg.index]],
+        bump,
+    )]
+    rule_counter: Box<Account<'info, RuleCounter>>,
+    #[account(
+        mut,
+        constraint = policy.is_rule_admin(&authority.key(), program_config.as_deref()) @ OCPErrorCode::InvalidAuthority,
+    )]
+    authority: Signer<'info>,
+    system_program: Program<'info, System>,
+    #[account(seeds = [ProgramConfig::SEED.as_bytes()], bump = program_config.bump[0])]
+    program_config: Option<Account<'info, ProgramConfig>>, // for the managed authorities
+}
+
+pub fn handler(ctx: Context<InitRuleCounterCtx>, arg: InitRuleCounterArg) -> Result<()> {
+    let rule_counter = &mut ctx.accounts.rule_counter;
+    rule_counter.version = 0;
+    rule_counter.bump = [ctx.bumps.rule_counter];
+    rule_counter.policy = ctx.accounts.policy.key();
+    rule_counter.index = arg.index;
+    rule_counter.count = 0;
+    ctx.accounts.policy.add_account()
+}
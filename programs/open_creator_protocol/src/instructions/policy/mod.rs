@@ -3,3 +3,63 @@ pub use init_policy::*;
 
 pub mod update_policy;
 pub use update_policy::*;
+
+pub mod get_royalty_quote;
+pub use get_royalty_quote::*;
+
+pub mod set_royalty_exemption;
+pub use set_royalty_exemption::*;
+
+pub mod init_rule_chunk;
+pub use init_rule_chunk::*;
+
+pub mod write_rule_chunk;
+pub use write_rule_chunk::*;
+
+pub mod init_list;
+pub use init_list::*;
+
+pub mod extend_list;
+pub use extend_list::*;
+
+pub mod shrink_list;
+pub use shrink_list::*;
+
+pub mod freeze_list;
+pub use freeze_list::*;
+
+pub mod simulate_policy;
+pub use simulate_policy::*;
+
+pub mod init_rule_counter;
+pub use init_rule_counter::*;
+
+pub mod propose_policy_update;
+pub use propose_policy_update::*;
+
+pub mod apply_policy_update;
+pub use apply_policy_update::*;
+
+pub mod cancel_policy_update;
+pub use cancel_policy_update::*;
+
+pub mod nominate_policy_authority;
+pub use nominate_policy_authority::*;
+
+pub mod accept_policy_authority;
+pub use accept_policy_authority::*;
+
+pub mod set_policy_roles;
+pub use set_policy_roles::*;
+
+pub mod set_program_config;
+pub use set_program_config::*;
+
+pub mod freeze_policy;
+pub use freeze_policy::*;
+
+pub mod set_policy_paused;
+pub use set_policy_paused::*;
+
+pub mod close_policy;
+pub use close_policy::*;
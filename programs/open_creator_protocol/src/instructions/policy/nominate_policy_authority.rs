@@ -0,0 +1,40 @@
+use crate::errors::OCPErrorCode;
+use crate::events::PolicyAuthorityNominated;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Default, AnchorSerialize, AnchorDeserialize)]
+pub struct NominatePolicyAuthorityArg {
+    pub pending_authority: Option<Pubkey>, // None withdraws the nomination
+}
+
+#[derive(Accounts)]
+pub struct NominatePolicyAuthorityCtx<'info> {
+    #[account(
+        mut,
+        constraint = !policy.authority_frozen @ OCPErrorCode::PolicyFrozen,
+        realloc = Policy::LEN, // policies created before the account grew are resized on nomination
+        realloc::payer = authority,
+        realloc::zero = false,
+    )]
+    policy: Box<Account<'info, Policy>>,
+    #[account(
+        mut,
+        constraint = policy.is_authority(&authority.key(), program_config.as_deref()) @ OCPErrorCode::InvalidAuthority,
+    )]
+    authority: Signer<'info>,
+    system_program: Program<'info, System>,
+    #[account(seeds = [ProgramConfig::SEED.as_bytes()], bump = program_config.bump[0])]
+    program_config: Option<Account<'info, ProgramConfig>>, // for the managed authorities
+}
+
+// the authority only changes when the nominee signs accept_policy_authority, so a wrong key can't take the policy
+pub fn handler(ctx: Context<NominatePolicyAuthorityCtx>, arg: NominatePolicyAuthorityArg) -> Result<()> {
+    let policy = &mut ctx.accounts.policy;
+    policy.pending_authority = arg.pending_authority;
+    emit!(PolicyAuthorityNominated {
+        policy: policy.key(),
+        pending_authority: policy.pending_authority,
+    });
+    Ok(())
+}
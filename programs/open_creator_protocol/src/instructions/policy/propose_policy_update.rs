@@ -0,0 +1,59 @@
+use crate::errors::OCPErrorCode;
+use crate::events::PolicyUpdateProposed;
+use crate::instructions::policy::update_policy::UpdatePolicyArg;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(arg: UpdatePolicyArg)]
+pub struct ProposePolicyUpdateCtx<'info> {
+    #[account(
+        mut,
+        constraint = arg.authority == policy.authority @ OCPErrorCode::InvalidAuthority,
+        realloc = Policy::LEN, // policies created before the account grew are resized to count the accounts
+        realloc::payer = authority,
+        realloc::zero = false,
+    )]
+    policy: Box<Account<'info, Policy>>,
+    #[account(
+        init,
+        payer = authority,
+        space = PendingPolicyUpdate::LEN,
+        seeds = [PendingPolicyUpdate::SEED.as_bytes(), policy.key().as_ref()],
+        bump,
+    )]
+    pending_policy_update: Box<Account<'info, PendingPolicyUpdate>>,
+    #[account(
+        mut,
+        constraint = policy.is_update_admin(&authority.key(), program_config.as_deref()) @ OCPErrorCode::InvalidAuthority,
+    )]
+    authority: Signer<'info>,
+    system_program: Program<'info, System>,
+    #[account(seeds = [ProgramConfig::SEED.as_bytes()], bump = program_config.bump[0])]
+    program_config: Option<Account<'info, ProgramConfig>>, // for the managed authorities
+}
+
+pub fn handler(ctx: Context<ProposePolicyUpdateCtx>, arg: UpdatePolicyArg) -> Result<()> {
+    let update = PolicyUpdate::from(arg);
+    ctx.accounts
+        .policy
+        .check_update_authority(&ctx.accounts.authority.key(), &update, ctx.accounts.program_config.as_deref())?;
+    // fails early instead of at apply_policy_update
+    (**ctx.accounts.policy).clone().update(update.clone())?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let pending_policy_update = &mut ctx.accounts.pending_policy_update;
+    pending_policy_update.version = 0;
+    pending_policy_update.bump = [ctx.bumps.pending_policy_update];
+    pending_policy_update.policy = ctx.accounts.policy.key();
+    pending_policy_update.proposed_at = now;
+    pending_policy_update.effective_at = now.checked_add(ctx.accounts.policy.update_delay).ok_or(OCPErrorCode::NumericalOverflow)?;
+    pending_policy_update.update = update;
+    ctx.accounts.policy.add_account()?;
+
+    emit!(PolicyUpdateProposed {
+        policy: pending_policy_update.policy,
+        effective_at: pending_policy_update.effective_at,
+    });
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use crate::errors::OCPErrorCode;
+use crate::events::PolicyPaused;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Default, AnchorSerialize, AnchorDeserialize)]
+pub struct SetPolicyPausedArg {
+    pub paused: bool, // false to resume the policy
+}
+
+#[derive(Accounts)]
+pub struct SetPolicyPausedCtx<'info> {
+    #[account(
+        mut,
+        constraint = !policy.frozen @ OCPErrorCode::PolicyFrozen,
+        realloc = Policy::LEN, // policies created before the account grew are resized on pause
+        realloc::payer = authority,
+        realloc::zero = false,
+    )]
+    policy: Box<Account<'info, Policy>>,
+    #[account(
+        mut,
+        constraint = policy.is_pause_guardian(&authority.key(), program_config.as_deref()) @ OCPErrorCode::InvalidAuthority,
+    )]
+    authority: Signer<'info>,
+    system_program: Program<'info, System>,
+    #[account(seeds = [ProgramConfig::SEED.as_bytes()], bump = program_config.bump[0])]
+    program_config: Option<Account<'info, ProgramConfig>>, // for the managed authorities
+}
+
+// the emergency stop of the pause guardian. It takes effect right away, even with a timelock, and every action of
+// the policy fails with PolicyPaused until the pause guardian resumes it
+pub fn handler(ctx: Context<SetPolicyPausedCtx>, arg: SetPolicyPausedArg) -> Result<()> {
+    let policy = &mut ctx.accounts.policy;
+    policy.paused = arg.paused;
+    emit!(PolicyPaused {
+        policy: policy.key(),
+        paused: policy.paused,
+    });
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use crate::errors::OCPErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Default, AnchorSerialize, AnchorDeserialize)]
+pub struct SetPolicyRolesArg {
+    pub rule_admin: Option<Pubkey>, // None for the authority
+    pub royalty_admin: Option<Pubkey>,
+    pub pause_guardian: Option<Pubkey>,
+    pub unmanaged: bool, // true to opt out of the managed authorities
+}
+
+#[derive(Accounts)]
+pub struct SetPolicyRolesCtx<'info> {
+    #[account(
+        mut,
+        constraint = !policy.frozen @ OCPErrorCode::PolicyFrozen,
+        realloc = Policy::LEN, // policies created before the account grew are resized on update
+        realloc::payer = authority,
+        realloc::zero = false,
+    )]
+    policy: Box<Account<'info, Policy>>,
+    #[account(
+        mut,
+        constraint = policy.is_authority(&authority.key(), program_config.as_deref()) @ OCPErrorCode::InvalidAuthority,
+    )]
+    authority: Signer<'info>,
+    system_program: Program<'info, System>,
+    #[account(seeds = [ProgramConfig::SEED.as_bytes()], bump = program_config.bump[0])]
+    program_config: Option<Account<'info, ProgramConfig>>, // for the managed authorities
+}
+
+pub fn handler(ctx: Context<SetPolicyRolesCtx>, arg: SetPolicyRolesArg) -> Result<()> {
+    let policy = &mut ctx.accounts.policy;
+    policy.rule_admin = arg.rule_admin;
+    policy.royalty_admin = arg.royalty_admin;
+    policy.pause_guardian = arg.pause_guardian;
+    policy.unmanaged = arg.unmanaged;
+    Ok(())
+}
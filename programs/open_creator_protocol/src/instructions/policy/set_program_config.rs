@@ -0,0 +1,40 @@
+use crate::errors::OCPErrorCode;
+use crate::program::OpenCreatorProtocol;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Default, AnchorSerialize, AnchorDeserialize)]
+pub struct SetProgramConfigArg {
+    pub managed_authorities: Vec<Pubkey>, // overwrites the existing managed_authorities
+}
+
+#[derive(Accounts)]
+pub struct SetProgramConfigCtx<'info> {
+    #[account(
+        init_if_needed,
+        payer = upgrade_authority,
+        space = ProgramConfig::LEN,
+        seeds = [ProgramConfig::SEED.as_bytes()],
+        bump,
+    )]
+    program_config: Box<Account<'info, ProgramConfig>>,
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()) @ OCPErrorCode::InvalidAuthority)]
+    program: Program<'info, OpenCreatorProtocol>,
+    #[account(constraint = program_data.upgrade_authority_address == Some(upgrade_authority.key()) @ OCPErrorCode::InvalidAuthority)]
+    program_data: Account<'info, ProgramData>,
+    #[account(mut)]
+    upgrade_authority: Signer<'info>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetProgramConfigCtx>, arg: SetProgramConfigArg) -> Result<()> {
+    if arg.managed_authorities.len() > ProgramConfig::MANAGED_AUTHORITIES_MAX {
+        msg!("managed_authorities must have at most {} keys", ProgramConfig::MANAGED_AUTHORITIES_MAX);
+        return Err(OCPErrorCode::InvalidProgramConfig.into());
+    }
+    let program_config = &mut ctx.accounts.program_config;
+    program_config.version = 0;
+    program_config.bump = [ctx.bumps.program_config];
+    program_config.managed_authorities = arg.managed_authorities;
+    Ok(())
+}
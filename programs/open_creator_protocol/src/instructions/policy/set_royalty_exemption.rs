@@ -0,0 +1,52 @@
+use crate::errors::OCPErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Default, AnchorSerialize, AnchorDeserialize)]
+pub struct SetRoyaltyExemptionArg {
+    pub wallets: Vec<Pubkey>,     // overwrites the existing wallets
+    pub program_ids: Vec<Pubkey>, // overwrites the existing program_ids
+}
+
+#[derive(Accounts)]
+#[instruction(arg: SetRoyaltyExemptionArg)]
+pub struct SetRoyaltyExemptionCtx<'info> {
+    #[account(
+        mut,
+        constraint = !policy.frozen @ OCPErrorCode::PolicyFrozen,
+        constraint = policy.update_delay == 0 @ OCPErrorCode::PolicyUpdateTimelocked, // see propose_policy_update
+        realloc = Policy::LEN, // policies created before the account grew are resized to count the accounts
+        realloc::payer = authority,
+        realloc::zero = false,
+    )]
+    policy: Box<Account<'info, Policy>>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = RoyaltyExemption::LEN,
+        seeds = [RoyaltyExemption::SEED.as_bytes(), policy.key().as_ref()],
+        bump,
+    )]
+    royalty_exemption: Box<Account<'info, RoyaltyExemption>>,
+    #[account(
+        mut,
+        constraint = policy.is_royalty_admin(&authority.key(), program_config.as_deref()) @ OCPErrorCode::InvalidAuthority,
+    )]
+    authority: Signer<'info>,
+    system_program: Program<'info, System>,
+    #[account(seeds = [ProgramConfig::SEED.as_bytes()], bump = program_config.bump[0])]
+    program_config: Option<Account<'info, ProgramConfig>>, // for the managed authorities
+}
+
+pub fn handler(ctx: Context<SetRoyaltyExemptionCtx>, arg: SetRoyaltyExemptionArg) -> Result<()> {
+    if ctx.accounts.royalty_exemption.policy == Pubkey::default() {
+        ctx.accounts.policy.add_account()?; // created by init_if_needed
+    }
+    let royalty_exemption = &mut ctx.accounts.royalty_exemption;
+    royalty_exemption.version = 0;
+    royalty_exemption.bump = [ctx.bumps.royalty_exemption];
+    royalty_exemption.policy = ctx.accounts.policy.key();
+    royalty_exemption.wallets = arg.wallets;
+    royalty_exemption.program_ids = arg.program_ids;
+    royalty_exemption.valid()
+}
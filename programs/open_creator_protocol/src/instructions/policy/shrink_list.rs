@@ -0,0 +1,38 @@
+use crate::errors::OCPErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Default, AnchorSerialize, AnchorDeserialize)]
+pub struct ShrinkListArg {
+    pub keys: Vec<Pubkey>, // must be in the list
+}
+
+#[derive(Accounts)]
+#[instruction(arg: ShrinkListArg)]
+pub struct ShrinkListCtx<'info> {
+    #[account(
+        mut,
+        constraint = list.authority == authority.key() @ OCPErrorCode::InvalidAuthority,
+        constraint = arg.keys.len() <= list.keys.len() @ OCPErrorCode::InvalidListAccount,
+        realloc = ListAccount::space(list.keys.len() - arg.keys.len()),
+        realloc::payer = authority,
+        realloc::zero = false,
+    )]
+    list: Box<Account<'info, ListAccount>>,
+    #[account(mut)]
+    authority: Signer<'info>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ShrinkListCtx>, arg: ShrinkListArg) -> Result<()> {
+    let list = &mut ctx.accounts.list;
+    for key in arg.keys.iter() {
+        match list.keys.binary_search(key) {
+            Ok(index) => {
+                list.keys.remove(index);
+            }
+            Err(_) => return Err(OCPErrorCode::InvalidListAccount.into()),
+        }
+    }
+    list.valid()
+}
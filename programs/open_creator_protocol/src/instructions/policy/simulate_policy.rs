@@ -0,0 +1,168 @@
+use crate::action::ActionCtx;
+use crate::errors::OCPErrorCode;
+use crate::merkle::MerkleProof;
+use crate::rule::{evaluate_json_rule, to_trace_status, trace_json_rule, ConditionError, ConditionTrace, RuleEvent, TRACE_STATUS_MET};
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::MAX_RETURN_DATA;
+use anchor_lang::solana_program::sysvar;
+use anchor_spl::metadata::MetadataAccount;
+use anchor_spl::token::Mint;
+use json_rules_engine_fork::Status;
+
+#[derive(Default, AnchorSerialize, AnchorDeserialize)]
+pub struct SimulatePolicyArg {
+    pub action: String,
+    pub payer: Option<Pubkey>,
+    pub from: Option<Pubkey>,
+    pub to: Option<Pubkey>,
+    pub program_ids: Option<Vec<Pubkey>>, // None to use the program ids of the simulated transaction
+    pub merkle_proofs: Vec<MerkleProof>,
+}
+
+#[derive(Default, AnchorSerialize, AnchorDeserialize)]
+pub struct RuleTrace {
+    pub rule: String, // "json_rule", "action_rule:<action>" or "rule_chunk:<index>"
+    pub status: u8,
+    pub conditions: Vec<ConditionTrace>,
+}
+
+#[derive(Default, AnchorSerialize, AnchorDeserialize)]
+pub struct PolicyTrace {
+    pub passed: bool,
+    pub truncated: bool, // the last conditions are left out when the trace doesn't fit in the return data
+    pub rules: Vec<RuleTrace>,
+    pub events: Vec<RuleEvent>,        // the events of the matched rules, applied when the policy passes
+    pub error: Option<ConditionError>, // the code and message of the PolicyDenied event, from the first failed rule
+}
+
+#[derive(Accounts)]
+pub struct SimulatePolicyCtx<'info> {
+    policy: Box<Account<'info, Policy>>,
+    mint: Box<Account<'info, Mint>>,
+    #[account(constraint = metadata.mint == mint.key() @ OCPErrorCode::InvalidMint)]
+    metadata: Option<Box<Account<'info, MetadataAccount>>>,
+    // optional, a new mint state is assumed without it, e.g. to simulate a wrap
+    #[account(
+        constraint = mint_state.mint == mint.key() @ OCPErrorCode::InvalidMint,
+        constraint = mint_state.policy == policy.key() @ OCPErrorCode::InvalidPolicyMintAssociation,
+    )]
+    mint_state: Option<Box<Account<'info, MintState>>>,
+    /// CHECK: This is not dangerous because the ID is checked with instructions sysvar
+    #[account(address = sysvar::instructions::id())]
+    instructions: UncheckedAccount<'info>,
+    // remaining_accounts: the rule chunks and the list accounts, same as the actions
+}
+
+impl PolicyTrace {
+    // the events of a matched rule are kept, and the error of the first rule that fails, as in Policy::evaluate
+    fn add_rule(&mut self, rule: String, status: Status, conditions: Vec<ConditionTrace>, events: Vec<RuleEvent>, error: Option<ConditionError>) {
+        if status == Status::Met {
+            self.events.extend(events);
+        } else if self.rules.iter().all(|x| x.status == TRACE_STATUS_MET) {
+            self.error = error;
+        }
+        self.rules.push(RuleTrace {
+            rule,
+            status: to_trace_status(status),
+            conditions,
+        });
+    }
+
+    // drops the last conditions until the borsh encoded trace is at most max_len bytes
+    fn truncate(&mut self, max_len: usize) -> Result<()> {
+        let mut len = self.try_to_vec()?.len();
+        while len > max_len {
+            let condition = self
+                .rules
+                .iter_mut()
+                .rev()
+                .find_map(|x| x.conditions.pop())
+                .ok_or(OCPErrorCode::InvalidPolicyEvaluation)?;
+            len -= condition.try_to_vec()?.len();
+            self.truncated = true;
+        }
+        Ok(())
+    }
+}
+
+fn get_action_ctx(accounts: &SimulatePolicyCtx, arg: SimulatePolicyArg) -> Result<ActionCtx> {
+    let mint_state = match &accounts.mint_state {
+        Some(mint_state) => mint_state.clone().into_inner(),
+        None => MintState {
+            mint: accounts.mint.key(),
+            policy: accounts.policy.key(),
+            ..Default::default()
+        },
+    };
+    let mut action_ctx = ActionCtx {
+        action: arg.action,
+        program_ids: vec![],
+        last_memo_data: None,
+        last_memo_signer: None,
+        merkle_proofs: vec![],
+        payer: arg.payer.map(|x| x.to_string()),
+        from: arg.from.map(|x| x.to_string()),
+        to: arg.to.map(|x| x.to_string()),
+        mint: accounts.mint.key().to_string(),
+        metadata: accounts.metadata.clone().map(|x| x.into()),
+        mint_account: Some(accounts.mint.clone().into()),
+        mint_state: mint_state.into(),
+        policy: Some((&**accounts.policy).into()),
+    };
+    action_ctx.parse_instructions(&accounts.instructions)?;
+    if let Some(program_ids) = arg.program_ids {
+        action_ctx.program_ids = program_ids.iter().map(|x| x.to_string()).collect();
+    }
+    action_ctx.merkle_proofs.extend(arg.merkle_proofs);
+    Ok(action_ctx)
+}
+
+// read-only, evaluates the policy like Policy::matches and returns the trace with set_return_data
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, SimulatePolicyCtx<'info>>, arg: SimulatePolicyArg) -> Result<PolicyTrace> {
+    let action_ctx = get_action_ctx(ctx.accounts, arg)?;
+    let policy = &ctx.accounts.policy;
+
+    let (rule, json_rule, compiled_rule) = match policy.get_action_rule(&action_ctx.action) {
+        Some(action_rule) => (
+            format!("action_rule:{}", action_rule.action),
+            Some(&action_rule.json_rule),
+            &action_rule.compiled_rule,
+        ),
+        None => ("json_rule".to_string(), policy.json_rule.as_ref(), &policy.compiled_rule),
+    };
+    let ((status, conditions), rule_events, rule_error) = match (compiled_rule, json_rule) {
+        (Some(compiled_rule), _) => {
+            let error = compiled_rule.conditions.find_error(&action_ctx, ctx.remaining_accounts).cloned();
+            (
+                compiled_rule.trace(&action_ctx, ctx.remaining_accounts),
+                compiled_rule.events.clone(),
+                error,
+            )
+        }
+        (None, Some(json_rule)) if !json_rule.is_empty() => {
+            let trace = trace_json_rule(json_rule.as_bytes(), &action_ctx, ctx.remaining_accounts).ok_or(OCPErrorCode::InvalidPolicyEvaluation)?;
+            let (_, events, error) =
+                evaluate_json_rule(json_rule.as_bytes(), &action_ctx, ctx.remaining_accounts).ok_or(OCPErrorCode::InvalidPolicyEvaluation)?;
+            (trace, events, error)
+        }
+        _ => ((Status::Met, vec![]), vec![], None),
+    };
+    let mut trace = PolicyTrace::default();
+    trace.add_rule(rule, status, conditions, rule_events, rule_error);
+
+    for index in 0..policy.rule_chunk_count {
+        let rule_chunk = RuleChunk::load(&policy.key(), index, ctx.remaining_accounts)?;
+        let (status, conditions) =
+            trace_json_rule(&rule_chunk.json_rule, &action_ctx, ctx.remaining_accounts).ok_or(OCPErrorCode::InvalidRuleChunk)?;
+        let (_, events, error) =
+            evaluate_json_rule(&rule_chunk.json_rule, &action_ctx, ctx.remaining_accounts).ok_or(OCPErrorCode::InvalidRuleChunk)?;
+        trace.add_rule(format!("rule_chunk:{}", index), status, conditions, events, error);
+    }
+
+    // the memo is checked with the events in Policy::matches, and a paused policy denies every action
+    let memo_missing = trace.events.contains(&RuleEvent::RequireMemo) && action_ctx.last_memo_data.is_none();
+    trace.passed = trace.rules.iter().all(|x| x.status == TRACE_STATUS_MET) && !memo_missing && !policy.paused;
+    trace.truncate(MAX_RETURN_DATA)?;
+    Ok(trace)
+}
@@ -1,38 +1,62 @@
 use crate::errors::OCPErrorCode;
-use crate::royalty::DynamicRoyalty;
+use crate::instructions::policy::init_policy::ActionRuleArg;
+use crate::royalty::{DynamicRoyalty, RoyaltyRecipient};
 use crate::state::*;
 use anchor_lang::prelude::*;
 
 #[derive(Default, AnchorSerialize, AnchorDeserialize)]
 pub struct UpdatePolicyArg {
-    pub authority: Pubkey,
+    pub authority: Pubkey,                       // must be the current authority, see nominate_policy_authority to change it
     pub json_rule: Option<String>,               // None will overwrite the existing field
     pub dynamic_royalty: Option<DynamicRoyalty>, // None will overwrite the existing field
+    pub royalty_split: Option<Vec<RoyaltyRecipient>>,
+    pub royalty_enforcement_mode: u8,
+    pub rule_chunk_count: u8,
+    pub action_rules: Vec<ActionRuleArg>, // empty will overwrite the existing field
+    pub update_delay: i64,                // 0 for no timelock
+}
+
+impl From<UpdatePolicyArg> for PolicyUpdate {
+    fn from(arg: UpdatePolicyArg) -> Self {
+        Self {
+            json_rule: arg.json_rule,
+            dynamic_royalty: arg.dynamic_royalty,
+            royalty_split: arg.royalty_split,
+            royalty_enforcement_mode: arg.royalty_enforcement_mode,
+            rule_chunk_count: arg.rule_chunk_count,
+            action_rules: arg.action_rules.into_iter().map(ActionRule::from).collect(),
+            update_delay: arg.update_delay,
+        }
+    }
 }
 
 #[derive(Accounts)]
 #[instruction(arg: UpdatePolicyArg)]
 pub struct UpdatePolicyCtx<'info> {
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = arg.authority == policy.authority @ OCPErrorCode::InvalidAuthority,
+        constraint = policy.update_delay == 0 @ OCPErrorCode::PolicyUpdateTimelocked, // see propose_policy_update
+        realloc = Policy::LEN, // policies created before the account grew are resized on update
+        realloc::payer = authority,
+        realloc::zero = false,
+    )]
     policy: Box<Account<'info, Policy>>,
     #[account(
-        constraint = (
-            authority.key() == policy.authority ||
-            authority.key().to_string() == Policy::MANAGED_AUTHORITY
-        ) @ OCPErrorCode::InvalidAuthority,
-        constraint = (
-            arg.authority.to_string() != Policy::MANAGED_AUTHORITY ||
-            authority.key().to_string() == Policy::MANAGED_AUTHORITY
-        ) @ OCPErrorCode::InvalidAuthority,
+        mut,
+        constraint = policy.is_update_admin(&authority.key(), program_config.as_deref()) @ OCPErrorCode::InvalidAuthority,
     )]
     authority: Signer<'info>,
     system_program: Program<'info, System>,
+    #[account(seeds = [ProgramConfig::SEED.as_bytes()], bump = program_config.bump[0])]
+    program_config: Option<Account<'info, ProgramConfig>>, // for the managed authorities
 }
 
 pub fn handler(ctx: Context<UpdatePolicyCtx>, arg: UpdatePolicyArg) -> Result<()> {
+    let update = PolicyUpdate::from(arg);
     let policy = &mut ctx.accounts.policy;
-    policy.json_rule = arg.json_rule;
-    policy.dynamic_royalty = arg.dynamic_royalty;
-    policy.authority = arg.authority;
-    policy.valid()
+    policy.check_update_authority(&ctx.accounts.authority.key(), &update, ctx.accounts.program_config.as_deref())?;
+    let rule_chunk_count = policy.rule_chunk_count;
+    policy.update(update)?;
+    policy.check_new_rule_chunks(rule_chunk_count, ctx.remaining_accounts) // the newly referenced rule chunks
 }
@@ -0,0 +1,50 @@
+use crate::errors::OCPErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Default, AnchorSerialize, AnchorDeserialize)]
+pub struct WriteRuleChunkArg {
+    pub index: u8,
+    pub offset: u32,   // the json_rule is truncated at offset before data is appended, use 0 to rewrite it
+    pub data: Vec<u8>, // the json_rule doesn't need to be complete until the policy's rule_chunk_count covers it
+}
+
+// only the chunks that the policy doesn't reference yet can be written, a referenced chunk is parsed on every action.
+// To rewrite one, lower the rule_chunk_count first, and the update that raises it again checks the chunk is complete
+
+#[derive(Accounts)]
+#[instruction(arg: WriteRuleChunkArg)]
+pub struct WriteRuleChunkCtx<'info> {
+    #[account(
+        constraint = !policy.frozen @ OCPErrorCode::PolicyFrozen,
+        constraint = policy.update_delay == 0 @ OCPErrorCode::PolicyUpdateTimelocked, // see propose_policy_update
+    )]
+    policy: Box<Account<'info, Policy>>,
+    #[account(
+        mut,
+        seeds = [RuleChunk::SEED.as_bytes(), policy.key().as_ref(), &[arg.index]],
+        bump = rule_chunk.bump[0],
+        constraint = arg.index >= policy.rule_chunk_count @ OCPErrorCode::InvalidRuleChunk,
+        constraint = arg.offset as usize <= rule_chunk.json_rule.len() @ OCPErrorCode::InvalidRuleChunk,
+        constraint = arg.offset as usize + arg.data.len() <= RuleChunk::JSON_RULE_MAX_LEN @ OCPErrorCode::InvalidRuleChunk,
+        realloc = RuleChunk::space(arg.offset as usize + arg.data.len()),
+        realloc::payer = authority,
+        realloc::zero = false,
+    )]
+    rule_chunk: Box<Account<'info, RuleChunk>>,
+    #[account(
+        mut,
+        constraint = policy.is_rule_admin(&authority.key(), program_config.as_deref()) @ OCPErrorCode::InvalidAuthority,
+    )]
+    authority: Signer<'info>,
+    system_program: Program<'info, System>,
+    #[account(seeds = [ProgramConfig::SEED.as_bytes()], bump = program_config.bump[0])]
+    program_config: Option<Account<'info, ProgramConfig>>, // for the managed authorities
+}
+
+pub fn handler(ctx: Context<WriteRuleChunkCtx>, arg: WriteRuleChunkArg) -> Result<()> {
+    let rule_chunk = &mut ctx.accounts.rule_chunk;
+    rule_chunk.json_rule.truncate(arg.offset as usize);
+    rule_chunk.json_rule.extend_from_slice(&arg.data);
+    Ok(())
+}
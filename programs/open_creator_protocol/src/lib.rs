@@ -1,9 +1,14 @@
 #![allow(clippy::result_large_err)]
 
 pub mod action;
+#[cfg(any(test, feature = "client"))]
+pub mod client;
 pub mod errors;
+pub mod events;
 pub mod instructions;
+pub mod merkle;
 pub mod royalty;
+pub mod rule;
 pub mod state;
 
 use anchor_lang::prelude::*;
@@ -23,6 +28,86 @@ pub mod open_creator_protocol {
         policy::update_policy::handler(ctx, arg)
     }
 
+    pub fn propose_policy_update(ctx: Context<ProposePolicyUpdateCtx>, arg: UpdatePolicyArg) -> Result<()> {
+        policy::propose_policy_update::handler(ctx, arg)
+    }
+
+    pub fn apply_policy_update(ctx: Context<ApplyPolicyUpdateCtx>) -> Result<()> {
+        policy::apply_policy_update::handler(ctx)
+    }
+
+    pub fn cancel_policy_update(ctx: Context<CancelPolicyUpdateCtx>) -> Result<()> {
+        policy::cancel_policy_update::handler(ctx)
+    }
+
+    pub fn nominate_policy_authority(ctx: Context<NominatePolicyAuthorityCtx>, arg: NominatePolicyAuthorityArg) -> Result<()> {
+        policy::nominate_policy_authority::handler(ctx, arg)
+    }
+
+    pub fn accept_policy_authority(ctx: Context<AcceptPolicyAuthorityCtx>) -> Result<()> {
+        policy::accept_policy_authority::handler(ctx)
+    }
+
+    pub fn set_policy_roles(ctx: Context<SetPolicyRolesCtx>, arg: SetPolicyRolesArg) -> Result<()> {
+        policy::set_policy_roles::handler(ctx, arg)
+    }
+
+    pub fn set_program_config(ctx: Context<SetProgramConfigCtx>, arg: SetProgramConfigArg) -> Result<()> {
+        policy::set_program_config::handler(ctx, arg)
+    }
+
+    pub fn freeze_policy(ctx: Context<FreezePolicyCtx>, arg: FreezePolicyArg) -> Result<()> {
+        policy::freeze_policy::handler(ctx, arg)
+    }
+
+    pub fn set_policy_paused(ctx: Context<SetPolicyPausedCtx>, arg: SetPolicyPausedArg) -> Result<()> {
+        policy::set_policy_paused::handler(ctx, arg)
+    }
+
+    pub fn close_policy<'info>(ctx: Context<'_, '_, '_, 'info, ClosePolicyCtx<'info>>) -> Result<()> {
+        policy::close_policy::handler(ctx)
+    }
+
+    pub fn set_royalty_exemption(ctx: Context<SetRoyaltyExemptionCtx>, arg: SetRoyaltyExemptionArg) -> Result<()> {
+        policy::set_royalty_exemption::handler(ctx, arg)
+    }
+
+    pub fn init_rule_chunk(ctx: Context<InitRuleChunkCtx>, arg: InitRuleChunkArg) -> Result<()> {
+        policy::init_rule_chunk::handler(ctx, arg)
+    }
+
+    pub fn write_rule_chunk(ctx: Context<WriteRuleChunkCtx>, arg: WriteRuleChunkArg) -> Result<()> {
+        policy::write_rule_chunk::handler(ctx, arg)
+    }
+
+    pub fn init_rule_counter(ctx: Context<InitRuleCounterCtx>, arg: InitRuleCounterArg) -> Result<()> {
+        policy::init_rule_counter::handler(ctx, arg)
+    }
+
+    pub fn init_list(ctx: Context<InitListCtx>) -> Result<()> {
+        policy::init_list::handler(ctx)
+    }
+
+    pub fn extend_list(ctx: Context<ExtendListCtx>, arg: ExtendListArg) -> Result<()> {
+        policy::extend_list::handler(ctx, arg)
+    }
+
+    pub fn shrink_list(ctx: Context<ShrinkListCtx>, arg: ShrinkListArg) -> Result<()> {
+        policy::shrink_list::handler(ctx, arg)
+    }
+
+    pub fn freeze_list(ctx: Context<FreezeListCtx>) -> Result<()> {
+        policy::freeze_list::handler(ctx)
+    }
+
+    pub fn get_royalty_quote(ctx: Context<GetRoyaltyQuoteCtx>, arg: GetRoyaltyQuoteArg) -> Result<RoyaltyQuote> {
+        policy::get_royalty_quote::handler(ctx, arg)
+    }
+
+    pub fn simulate_policy<'info>(ctx: Context<'_, '_, '_, 'info, SimulatePolicyCtx<'info>>, arg: SimulatePolicyArg) -> Result<PolicyTrace> {
+        policy::simulate_policy::handler(ctx, arg)
+    }
+
     pub fn wrap<'info>(ctx: Context<'_, '_, '_, 'info, WrapCtx<'info>>) -> Result<()> {
         nft_proxy::wrap::handler(ctx)
     }
@@ -51,6 +136,10 @@ pub mod open_creator_protocol {
         nft_proxy::transfer::handler(ctx)
     }
 
+    pub fn transfer_with_payment<'info>(ctx: Context<'_, '_, '_, 'info, TransferWithPaymentCtx<'info>>, arg: TransferWithPaymentArg) -> Result<()> {
+        nft_proxy::transfer_with_payment::handler(ctx, arg)
+    }
+
     pub fn lock<'info>(ctx: Context<'_, '_, '_, 'info, LockCtx<'info>>) -> Result<()> {
         nft_proxy::lock::handler(ctx)
     }
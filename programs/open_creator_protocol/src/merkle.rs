@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+// the leaves and the nodes are domain separated, so that a node can't be passed off as a leaf. The pairs are
+// sorted before hashing, so a proof doesn't need to say on which side each sibling is
+const MERKLE_LEAF_PREFIX: &[u8] = &[0];
+const MERKLE_NODE_PREFIX: &[u8] = &[1];
+
+pub type MerkleNode = [u8; 32];
+
+// a proof that the leaf is in the Merkle tree, from the leaf's sibling up to the child of the root
+#[derive(Clone, Debug, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub struct MerkleProof {
+    pub leaf: Pubkey,
+    pub proof: Vec<[u8; 32]>,
+}
+
+// the memo form of the merkle proofs, keyed by the leaf, with the nodes in base58. e.g.
+// {"merkle_proofs":{"<leaf>":["<node>","<node>"]}}
+#[derive(Default, Deserialize)]
+pub struct MerkleProofMemo {
+    #[serde(default)]
+    pub merkle_proofs: BTreeMap<String, Vec<String>>,
+}
+
+impl MerkleProofMemo {
+    // an empty list when the memo is not json, or a node is not base58
+    pub fn parse(memo_data: &str) -> Vec<MerkleProof> {
+        let memo = match serde_json::from_str::<MerkleProofMemo>(memo_data) {
+            Ok(memo) => memo,
+            Err(_) => return vec![],
+        };
+        memo.merkle_proofs
+            .iter()
+            .filter_map(|(leaf, proof)| {
+                Some(MerkleProof {
+                    leaf: Pubkey::from_str(leaf).ok()?,
+                    proof: proof
+                        .iter()
+                        .map(|node| Pubkey::from_str(node).ok().map(|x| x.to_bytes()))
+                        .collect::<Option<Vec<_>>>()?,
+                })
+            })
+            .collect()
+    }
+}
+
+pub fn hash_leaf(leaf: &Pubkey) -> MerkleNode {
+    hashv(&[MERKLE_LEAF_PREFIX, leaf.as_ref()]).to_bytes()
+}
+
+pub fn hash_nodes(a: &MerkleNode, b: &MerkleNode) -> MerkleNode {
+    match a <= b {
+        true => hashv(&[MERKLE_NODE_PREFIX, a, b]).to_bytes(),
+        false => hashv(&[MERKLE_NODE_PREFIX, b, a]).to_bytes(),
+    }
+}
+
+impl MerkleProof {
+    pub fn verify(&self, root: &MerkleNode) -> bool {
+        self.proof.iter().fold(hash_leaf(&self.leaf), |node, sibling| hash_nodes(&node, sibling)) == *root
+    }
+}
+
+// true when one of the proofs shows that the key is in the tree of the root
+pub fn verify_merkle_proofs(root: &MerkleNode, key: &Pubkey, proofs: &[MerkleProof]) -> bool {
+    proofs.iter().any(|proof| proof.leaf == *key && proof.verify(root))
+}
+
+// builds the root and the proofs off-chain. The odd node of a layer is carried up as is, without a sibling
+#[cfg(any(test, feature = "client"))]
+pub struct MerkleTree {
+    leaves: Vec<Pubkey>,
+    layers: Vec<Vec<MerkleNode>>,
+}
+
+#[cfg(any(test, feature = "client"))]
+impl MerkleTree {
+    pub fn new(leaves: &[Pubkey]) -> Self {
+        let mut layers = vec![leaves.iter().map(hash_leaf).collect::<Vec<_>>()];
+        while layers.last().expect("layers should not be empty").len() > 1 {
+            let layer = layers.last().expect("layers should not be empty");
+            let next = layer
+                .chunks(2)
+                .map(|pair| match pair {
+                    [a, b] => hash_nodes(a, b),
+                    _ => pair[0],
+                })
+                .collect::<Vec<_>>();
+            layers.push(next);
+        }
+        Self {
+            leaves: leaves.to_vec(),
+            layers,
+        }
+    }
+
+    // the root of an empty tree is all zeros, which no proof verifies against
+    pub fn root(&self) -> MerkleNode {
+        self.layers.last().and_then(|x| x.first().copied()).unwrap_or_default()
+    }
+
+    // the base58 root, the value of the merkle operators in a json_rule
+    pub fn root_string(&self) -> String {
+        Pubkey::new_from_array(self.root()).to_string()
+    }
+
+    pub fn get_proof(&self, leaf: &Pubkey) -> Option<MerkleProof> {
+        let mut index = self.leaves.iter().position(|x| x == leaf)?;
+        let mut proof = vec![];
+        for layer in &self.layers[..self.layers.len() - 1] {
+            if let Some(sibling) = layer.get(index ^ 1) {
+                proof.push(*sibling);
+            }
+            index /= 2;
+        }
+        Some(MerkleProof { leaf: *leaf, proof })
+    }
+
+    // the memo data that carries the proofs, see MerkleProofMemo
+    pub fn to_memo(proofs: &[MerkleProof]) -> String {
+        let merkle_proofs = proofs
+            .iter()
+            .map(|x| {
+                (
+                    x.leaf.to_string(),
+                    x.proof.iter().map(|node| Pubkey::new_from_array(*node).to_string()).collect::<Vec<_>>(),
+                )
+            })
+            .collect::<BTreeMap<_, _>>();
+        serde_json::json!({ "merkle_proofs": merkle_proofs }).to_string()
+    }
+}
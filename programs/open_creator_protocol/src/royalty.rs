@@ -2,9 +2,23 @@ use crate::errors::OCPErrorCode;
 use anchor_lang::prelude::Result;
 use anchor_lang::prelude::*;
 use anchor_lang::{AnchorDeserialize, AnchorSerialize};
+use anchor_spl::metadata::{Metadata, MetadataAccount};
+use anchor_spl::token::TokenAccount;
 use serde::{Deserialize, Serialize};
+use solana_program::instruction::Instruction;
+use solana_program::system_program;
+use solana_program::sysvar::instructions::{load_current_index_checked, load_instruction_at_checked};
 
 pub const DYNAMIC_ROYALTY_KIND_PRICE_LINEAR: u8 = 0;
+pub const DYNAMIC_ROYALTY_KIND_PRICE_PIECEWISE_LINEAR: u8 = 1;
+pub const DYNAMIC_ROYALTY_KIND_HOLDING_TIME_LINEAR: u8 = 2;
+pub const DYNAMIC_ROYALTY_KIND_HOLDER_DISCOUNT: u8 = 3;
+
+pub const PRICE_PIECEWISE_LINEAR_MAX_POINTS: usize = 6;
+
+pub const ROYALTY_ENFORCEMENT_MODE_NONE: u8 = 0;
+// transfer checks that the system program transfers directly before it in the same transaction paid the royalty
+pub const ROYALTY_ENFORCEMENT_MODE_INSTRUCTION_INTROSPECTION: u8 = 1;
 
 #[derive(Default, Clone, Serialize, Deserialize, AnchorSerialize, AnchorDeserialize)]
 pub struct DynamicRoyaltyPriceLinear {
@@ -67,14 +81,158 @@ impl DynamicRoyaltyPriceLinear {
     }
 }
 
+#[derive(Default, Clone, Copy, Serialize, Deserialize, AnchorSerialize, AnchorDeserialize)]
+pub struct DynamicRoyaltyPricePoint {
+    // size: 8 + 2 = 10
+    pub price: u64,
+    pub multiplier_bp: u16,
+}
+
+#[derive(Default, Clone, Serialize, Deserialize, AnchorSerialize, AnchorDeserialize)]
+pub struct DynamicRoyaltyPricePiecewiseLinear {
+    // size: 1 + 10 * 6 = 61
+    pub num_points: u8,
+    pub points: [DynamicRoyaltyPricePoint; PRICE_PIECEWISE_LINEAR_MAX_POINTS], // only the first num_points are used
+}
+
+impl DynamicRoyaltyPricePiecewiseLinear {
+    fn active_points(&self) -> &[DynamicRoyaltyPricePoint] {
+        &self.points[..(self.num_points as usize).min(PRICE_PIECEWISE_LINEAR_MAX_POINTS)]
+    }
+
+    pub fn valid(&self) -> Result<()> {
+        if self.num_points < 2 || self.num_points as usize > PRICE_PIECEWISE_LINEAR_MAX_POINTS {
+            msg!("num_points must be between 2 and {}", PRICE_PIECEWISE_LINEAR_MAX_POINTS);
+            return Err(OCPErrorCode::InvalidDynamicRoyalty.into());
+        }
+        if self.active_points().windows(2).any(|w| w[0].price >= w[1].price) {
+            msg!("points must be sorted by strictly increasing price");
+            return Err(OCPErrorCode::InvalidDynamicRoyalty.into());
+        }
+        Ok(())
+    }
+
+    pub fn get_royalty_bp(&self, price: u64, royalty_bp: u16) -> Result<u16> {
+        let points = self.active_points();
+        let first = points.first().ok_or(OCPErrorCode::InvalidDynamicRoyalty)?;
+        let last = points.last().ok_or(OCPErrorCode::InvalidDynamicRoyalty)?;
+        if price <= first.price {
+            return Ok(DynamicRoyalty::safe_mul_bp(first.multiplier_bp, royalty_bp));
+        }
+        if price >= last.price {
+            return Ok(DynamicRoyalty::safe_mul_bp(last.multiplier_bp, royalty_bp));
+        }
+
+        // find the segment that the price falls into, and then it's the same as the price linear
+        let segment = points
+            .windows(2)
+            .find(|w| price <= w[1].price)
+            .ok_or(OCPErrorCode::InvalidDynamicRoyalty)?;
+        DynamicRoyaltyPriceLinear {
+            price_mint: None,
+            start_price: segment[0].price,
+            end_price: segment[1].price,
+            start_multiplier_bp: segment[0].multiplier_bp,
+            end_multiplier_bp: segment[1].multiplier_bp,
+        }
+        .get_royalty_bp(price, royalty_bp)
+    }
+}
+
+#[derive(Default, Clone, Serialize, Deserialize, AnchorSerialize, AnchorDeserialize)]
+pub struct DynamicRoyaltyHoldingTimeLinear {
+    // size: 8 + 8 + 2 + 2 = 20
+    pub start_duration: i64, // in seconds, how long the seller has held the token
+    pub end_duration: i64,
+    pub start_multiplier_bp: u16, // usually higher than end_multiplier_bp to discourage flipping
+    pub end_multiplier_bp: u16,
+}
+
+impl DynamicRoyaltyHoldingTimeLinear {
+    pub fn valid(&self) -> Result<()> {
+        if self.start_duration < 0 {
+            msg!("start_duration must be greater than or equal to 0");
+            return Err(OCPErrorCode::InvalidDynamicRoyalty.into());
+        }
+        if self.start_duration > self.end_duration {
+            msg!("start_duration must be less than or equal to end_duration");
+            return Err(OCPErrorCode::InvalidDynamicRoyalty.into());
+        }
+        Ok(())
+    }
+
+    pub fn get_royalty_bp(&self, holding_duration: i64, royalty_bp: u16) -> Result<u16> {
+        // same curve as the price linear, with the holding duration as the x axis
+        DynamicRoyaltyPriceLinear {
+            price_mint: None,
+            start_price: self.start_duration.max(0) as u64,
+            end_price: self.end_duration.max(0) as u64,
+            start_multiplier_bp: self.start_multiplier_bp,
+            end_multiplier_bp: self.end_multiplier_bp,
+        }
+        .get_royalty_bp(holding_duration.max(0) as u64, royalty_bp)
+    }
+}
+
+#[derive(Default, Clone, Serialize, Deserialize, AnchorSerialize, AnchorDeserialize)]
+pub struct DynamicRoyaltyHolderDiscount {
+    // size: 32 + 2 = 34
+    pub collection_mint: Pubkey, // the verified collection of the pass
+    pub discount_bp: u16,        // relative to the royalty_bp, e.g. 2500 means 25% off
+}
+
+impl DynamicRoyaltyHolderDiscount {
+    pub fn valid(&self) -> Result<()> {
+        if self.discount_bp > 10000 {
+            msg!("discount_bp must be less than or equal to 10000");
+            return Err(OCPErrorCode::InvalidDynamicRoyalty.into());
+        }
+        Ok(())
+    }
+
+    // the holder proves the pass with one of its token accounts and the metadata of the token account's mint
+    pub fn is_pass_holder(&self, holder: &Pubkey, pass_token_account: &AccountInfo, pass_metadata: &AccountInfo) -> Result<bool> {
+        if pass_token_account.owner != &anchor_spl::token::ID || pass_metadata.owner != &Metadata::id() {
+            return Ok(false);
+        }
+        let token_account = TokenAccount::try_deserialize(&mut &pass_token_account.try_borrow_data()?[..])?;
+        if token_account.owner != *holder || token_account.amount == 0 {
+            return Ok(false);
+        }
+        let (metadata_key, _) = Pubkey::find_program_address(&[b"metadata", Metadata::id().as_ref(), token_account.mint.as_ref()], &Metadata::id());
+        if pass_metadata.key() != metadata_key {
+            return Ok(false);
+        }
+        let metadata = MetadataAccount::try_deserialize(&mut &pass_metadata.try_borrow_data()?[..])?;
+        Ok(matches!(&metadata.collection, Some(collection) if collection.verified && collection.key == self.collection_mint))
+    }
+
+    pub fn get_royalty_bp(&self, is_pass_holder: bool, royalty_bp: u16) -> Result<u16> {
+        if !is_pass_holder {
+            return Ok(royalty_bp);
+        }
+        let multiplier_bp = 10000u16.checked_sub(self.discount_bp).ok_or(OCPErrorCode::NumericalOverflow)?;
+        Ok(DynamicRoyalty::safe_mul_bp(multiplier_bp, royalty_bp))
+    }
+}
+
 #[derive(Default, Clone, Serialize, Deserialize, AnchorSerialize, AnchorDeserialize)]
 pub struct DynamicRoyalty {
-    // size: 1 + 1 + 3 + 53 + 32 * 4 = 186
+    // size: 1 + 1 + 3 + 54 + 62 + 21 + 35 + 3 + 3 + 9 + 26 + 32 * 3 = 314
+    // only the None encodings of the new fields fit in the 128 reserved bytes of the old layout, which keeps the existing
+    // accounts deserializable. Once a new kind or bound is set, the struct outgrows the old layout, and the policy needs
+    // the realloc to Policy::LEN of update_policy and apply_policy_update
     pub version: u8,
     pub kind: u8,
     pub override_royalty_bp: Option<u16>, // if not set, we should use the one from metadata
     pub kind_price_linear: Option<DynamicRoyaltyPriceLinear>,
-    pub _reserved_0: [u8; 32],
+    pub kind_price_piecewise_linear: Option<DynamicRoyaltyPricePiecewiseLinear>, // price is always in SOL
+    pub kind_holding_time_linear: Option<DynamicRoyaltyHoldingTimeLinear>,
+    pub kind_holder_discount: Option<DynamicRoyaltyHolderDiscount>,
+    pub min_royalty_bp: Option<u16>, // the royalty_bp of any kind is clamped to [min_royalty_bp, max_royalty_bp]
+    pub max_royalty_bp: Option<u16>,
+    pub min_royalty_amount: Option<u64>, // in lamports, or in the smallest unit of the price_mint
+    pub _reserved_0: [u8; 26],
     pub _reserved_1: [u8; 32],
     pub _reserved_2: [u8; 32],
     pub _reserved_3: [u8; 32],
@@ -96,6 +254,16 @@ impl DynamicRoyalty {
             msg!("base_royalty_bp must be less than or equal to 10000");
             return Err(OCPErrorCode::InvalidDynamicRoyalty.into());
         }
+        if self.min_royalty_bp.unwrap_or(0) > 10000 || self.max_royalty_bp.unwrap_or(0) > 10000 {
+            msg!("min_royalty_bp and max_royalty_bp must be less than or equal to 10000");
+            return Err(OCPErrorCode::InvalidDynamicRoyalty.into());
+        }
+        if let (Some(min_royalty_bp), Some(max_royalty_bp)) = (self.min_royalty_bp, self.max_royalty_bp) {
+            if min_royalty_bp > max_royalty_bp {
+                msg!("min_royalty_bp must be less than or equal to max_royalty_bp");
+                return Err(OCPErrorCode::InvalidDynamicRoyalty.into());
+            }
+        }
         match self.kind {
             DYNAMIC_ROYALTY_KIND_PRICE_LINEAR => {
                 if self.kind_price_linear.is_none() {
@@ -104,6 +272,27 @@ impl DynamicRoyalty {
                 }
                 self.kind_price_linear.as_ref().unwrap().valid()?;
             }
+            DYNAMIC_ROYALTY_KIND_PRICE_PIECEWISE_LINEAR => {
+                if self.kind_price_piecewise_linear.is_none() {
+                    msg!("kind_price_piecewise_linear must be set for DYNAMIC_ROYALTY_KIND_PRICE_PIECEWISE_LINEAR");
+                    return Err(OCPErrorCode::InvalidDynamicRoyalty.into());
+                }
+                self.kind_price_piecewise_linear.as_ref().unwrap().valid()?;
+            }
+            DYNAMIC_ROYALTY_KIND_HOLDING_TIME_LINEAR => {
+                if self.kind_holding_time_linear.is_none() {
+                    msg!("kind_holding_time_linear must be set for DYNAMIC_ROYALTY_KIND_HOLDING_TIME_LINEAR");
+                    return Err(OCPErrorCode::InvalidDynamicRoyalty.into());
+                }
+                self.kind_holding_time_linear.as_ref().unwrap().valid()?;
+            }
+            DYNAMIC_ROYALTY_KIND_HOLDER_DISCOUNT => {
+                if self.kind_holder_discount.is_none() {
+                    msg!("kind_holder_discount must be set for DYNAMIC_ROYALTY_KIND_HOLDER_DISCOUNT");
+                    return Err(OCPErrorCode::InvalidDynamicRoyalty.into());
+                }
+                self.kind_holder_discount.as_ref().unwrap().valid()?;
+            }
             _ => {
                 msg!("Invalid DynamicRoyalty kind");
                 return Err(OCPErrorCode::InvalidDynamicRoyalty.into());
@@ -112,7 +301,39 @@ impl DynamicRoyalty {
         Ok(())
     }
 
-    pub fn get_royalty_bp(&self, price: u64, metadata_royalty_bp: u16) -> u16 {
+    pub fn get_price_mint(&self) -> Option<Pubkey> {
+        match self.kind {
+            DYNAMIC_ROYALTY_KIND_PRICE_LINEAR => self.kind_price_linear.as_ref().and_then(|x| x.price_mint),
+            _ => None,
+        }
+    }
+
+    // pass_accounts start with the [token_account, metadata] of the buyer's pass, only used by DYNAMIC_ROYALTY_KIND_HOLDER_DISCOUNT.
+    // Without a pass, they start with the rule chunks or the list accounts, which are not a pass
+    pub fn is_pass_holder(&self, holder: &Pubkey, pass_accounts: &[AccountInfo]) -> Result<bool> {
+        match (self.kind, &self.kind_holder_discount, pass_accounts) {
+            (DYNAMIC_ROYALTY_KIND_HOLDER_DISCOUNT, Some(holder_discount), [pass_token_account, pass_metadata, ..]) => {
+                holder_discount.is_pass_holder(holder, pass_token_account, pass_metadata)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    // holding_duration is how long the seller has held the token, in seconds
+    // is_pass_holder is whether the buyer holds a pass of the holder discount collection
+    pub fn get_royalty_bp(&self, price: u64, holding_duration: i64, is_pass_holder: bool, metadata_royalty_bp: u16) -> u16 {
+        let royalty_bp = self.get_unbounded_royalty_bp(price, holding_duration, is_pass_holder, metadata_royalty_bp);
+        let royalty_bp = royalty_bp.min(self.max_royalty_bp.unwrap_or(10000));
+        royalty_bp.max(self.min_royalty_bp.unwrap_or(0))
+    }
+
+    // the royalty amount never goes below min_royalty_amount, but never goes above the price either
+    pub fn get_royalty_amount(&self, price: u64, royalty_bp: u16) -> Result<u64> {
+        let amount = get_royalty_amount(price, royalty_bp)?;
+        Ok(amount.max(self.min_royalty_amount.unwrap_or(0).min(price)))
+    }
+
+    fn get_unbounded_royalty_bp(&self, price: u64, holding_duration: i64, is_pass_holder: bool, metadata_royalty_bp: u16) -> u16 {
         let royalty_bp = self.override_royalty_bp.unwrap_or(metadata_royalty_bp);
 
         match self.kind {
@@ -122,14 +343,140 @@ impl DynamicRoyalty {
                 .expect("kind_price_linear should not be empty")
                 .get_royalty_bp(price, royalty_bp)
                 .unwrap_or(royalty_bp),
+            DYNAMIC_ROYALTY_KIND_PRICE_PIECEWISE_LINEAR => self
+                .kind_price_piecewise_linear
+                .as_ref()
+                .expect("kind_price_piecewise_linear should not be empty")
+                .get_royalty_bp(price, royalty_bp)
+                .unwrap_or(royalty_bp),
+            DYNAMIC_ROYALTY_KIND_HOLDING_TIME_LINEAR => self
+                .kind_holding_time_linear
+                .as_ref()
+                .expect("kind_holding_time_linear should not be empty")
+                .get_royalty_bp(holding_duration, royalty_bp)
+                .unwrap_or(royalty_bp),
+            DYNAMIC_ROYALTY_KIND_HOLDER_DISCOUNT => self
+                .kind_holder_discount
+                .as_ref()
+                .expect("kind_holder_discount should not be empty")
+                .get_royalty_bp(is_pass_holder, royalty_bp)
+                .unwrap_or(royalty_bp),
             _ => royalty_bp,
         }
     }
 }
 
+#[derive(Default, Clone, PartialEq, Debug, Serialize, Deserialize, AnchorSerialize, AnchorDeserialize)]
+pub struct RoyaltyRecipient {
+    // size: 32 + 1 = 33
+    pub address: Pubkey,
+    pub share: u8, // in percentage, same as the metadata creators' share
+}
+
+pub fn get_royalty_amount(price: u64, royalty_bp: u16) -> Result<u64> {
+    let amount = (price as u128)
+        .checked_mul(royalty_bp as u128)
+        .ok_or(OCPErrorCode::NumericalOverflow)?
+        .checked_div(10000)
+        .ok_or(OCPErrorCode::NumericalOverflow)?;
+    u64::try_from(amount).map_err(|_| OCPErrorCode::NumericalOverflow.into())
+}
+
+// split the royalty amount by the creators' shares (in percentage), the dust stays with the payer
+pub fn split_royalty_amount(amount: u64, shares: &[u8]) -> Result<Vec<u64>> {
+    shares
+        .iter()
+        .map(|share| {
+            let share_amount = (amount as u128)
+                .checked_mul(*share as u128)
+                .ok_or(OCPErrorCode::NumericalOverflow)?
+                .checked_div(100)
+                .ok_or(OCPErrorCode::NumericalOverflow)?;
+            u64::try_from(share_amount).map_err(|_| OCPErrorCode::NumericalOverflow.into())
+        })
+        .collect()
+}
+
+// the payment_mint account must be passed exactly when the policy sets a price_mint, and be that mint
+pub fn check_payment_mint(price_mint: Option<Pubkey>, payment_mint: Option<Pubkey>) -> Result<()> {
+    match (price_mint, payment_mint) {
+        (None, None) => Ok(()),
+        (Some(price_mint), Some(payment_mint)) if price_mint == payment_mint => Ok(()),
+        _ => Err(OCPErrorCode::InvalidPriceMint.into()),
+    }
+}
+
+// the price_mint token account of the royalty recipient, for the royalties paid in an SPL token
+pub fn check_recipient_token_account(recipient_account: &AccountInfo, recipient: &Pubkey, price_mint: &Pubkey) -> Result<()> {
+    if recipient_account.owner != &anchor_spl::token::ID {
+        return Err(OCPErrorCode::InvalidRoyaltyRecipient.into());
+    }
+    let recipient_token_account = TokenAccount::try_deserialize(&mut &recipient_account.try_borrow_data()?[..])?;
+    if recipient_token_account.owner != *recipient || recipient_token_account.mint != *price_mint {
+        return Err(OCPErrorCode::InvalidRoyaltyRecipient.into());
+    }
+    Ok(())
+}
+
+// the sale price declared in a memo of the same transaction, e.g. {"price":1000000000}
+#[derive(Default, Serialize, Deserialize)]
+pub struct RoyaltyPaymentMemo {
+    pub price: u64,
+}
+
+impl RoyaltyPaymentMemo {
+    pub fn parse(memo_data: Option<&String>) -> Result<Self> {
+        let memo_data = memo_data.ok_or(OCPErrorCode::InvalidRoyaltyPaymentMemo)?;
+        serde_json::from_str::<RoyaltyPaymentMemo>(memo_data).map_err(|_| OCPErrorCode::InvalidRoyaltyPaymentMemo.into())
+    }
+}
+
+// (from, to, lamports) of a system program transfer instruction
+pub fn parse_system_transfer(ix: &Instruction) -> Option<(Pubkey, Pubkey, u64)> {
+    if ix.program_id != system_program::ID || ix.accounts.len() < 2 || ix.data.len() != 12 {
+        return None;
+    }
+    // bincode of SystemInstruction::Transfer { lamports }: u32 variant index 2, then u64 lamports
+    if ix.data[..4] != 2u32.to_le_bytes() {
+        return None;
+    }
+    let lamports = u64::from_le_bytes(ix.data[4..12].try_into().ok()?);
+    Some((ix.accounts[0].pubkey, ix.accounts[1].pubkey, lamports))
+}
+
+// adds the lamports of ix to paid if it's a system program transfer from one of the senders to one of the recipients
+pub fn add_paid_lamports(paid: &mut [u64], ix: &Instruction, senders: &[Pubkey], recipients: &[Pubkey]) -> Result<bool> {
+    let (from, to, lamports) = match parse_system_transfer(ix) {
+        Some(transfer) => transfer,
+        None => return Ok(false),
+    };
+    let j = match recipients.iter().position(|x| *x == to) {
+        Some(j) if senders.contains(&from) => j,
+        _ => return Ok(false),
+    };
+    paid[j] = paid[j].checked_add(lamports).ok_or(OCPErrorCode::NumericalOverflow)?;
+    Ok(true)
+}
+
+// the lamports paid to each of the recipients by the system program transfers directly before the current
+// instruction. Only the unbroken run of payments from the senders counts, so that a payment is bound to one transfer
+pub fn get_paid_lamports(ixs: &AccountInfo, senders: &[Pubkey], recipients: &[Pubkey]) -> Result<Vec<u64>> {
+    let mut paid = vec![0u64; recipients.len()];
+    let current_index = load_current_index_checked(ixs)?;
+    for i in (0..current_index).rev() {
+        let ix = load_instruction_at_checked(i.into(), ixs)?;
+        if !add_paid_lamports(&mut paid, &ix, senders, recipients)? {
+            break;
+        }
+    }
+    Ok(paid)
+}
+
 #[cfg(test)]
 mod tests {
+    use anchor_spl::token::spl_token;
     use solana_program::native_token::LAMPORTS_PER_SOL;
+    use solana_program::program_pack::Pack;
 
     use super::*;
 
@@ -203,18 +550,15 @@ mod tests {
                 kind: DYNAMIC_ROYALTY_KIND_PRICE_LINEAR,
                 override_royalty_bp: None,
                 kind_price_linear: Some(price_linear),
-                _reserved_0: [0; 32],
-                _reserved_1: [0; 32],
-                _reserved_2: [0; 32],
-                _reserved_3: [0; 32],
+                ..Default::default()
             };
             let metadat_roaylty_bp = 1000;
 
-            assert_eq!(dynamic_royalty.get_royalty_bp(0, metadat_roaylty_bp), 1000);
-            assert_eq!(dynamic_royalty.get_royalty_bp(100, metadat_roaylty_bp), 1000);
-            assert_eq!(dynamic_royalty.get_royalty_bp(1000, metadat_roaylty_bp), 10);
-            assert_eq!(dynamic_royalty.get_royalty_bp(10000, metadat_roaylty_bp), 10);
-            assert_eq!(dynamic_royalty.get_royalty_bp(500, metadat_roaylty_bp), 560);
+            assert_eq!(dynamic_royalty.get_royalty_bp(0, 0, false, metadat_roaylty_bp), 1000);
+            assert_eq!(dynamic_royalty.get_royalty_bp(100, 0, false, metadat_roaylty_bp), 1000);
+            assert_eq!(dynamic_royalty.get_royalty_bp(1000, 0, false, metadat_roaylty_bp), 10);
+            assert_eq!(dynamic_royalty.get_royalty_bp(10000, 0, false, metadat_roaylty_bp), 10);
+            assert_eq!(dynamic_royalty.get_royalty_bp(500, 0, false, metadat_roaylty_bp), 560);
         }
 
         // price linear asc
@@ -231,18 +575,15 @@ mod tests {
                 kind: DYNAMIC_ROYALTY_KIND_PRICE_LINEAR,
                 override_royalty_bp: None,
                 kind_price_linear: Some(price_linear),
-                _reserved_0: [0; 32],
-                _reserved_1: [0; 32],
-                _reserved_2: [0; 32],
-                _reserved_3: [0; 32],
+                ..Default::default()
             };
             let metadat_roaylty_bp = 1000;
 
-            assert_eq!(dynamic_royalty.get_royalty_bp(0, metadat_roaylty_bp), 500);
-            assert_eq!(dynamic_royalty.get_royalty_bp(100, metadat_roaylty_bp), 500);
-            assert_eq!(dynamic_royalty.get_royalty_bp(1000, metadat_roaylty_bp), 2000);
-            assert_eq!(dynamic_royalty.get_royalty_bp(10000, metadat_roaylty_bp), 2000);
-            assert_eq!(dynamic_royalty.get_royalty_bp(500, metadat_roaylty_bp), 1166);
+            assert_eq!(dynamic_royalty.get_royalty_bp(0, 0, false, metadat_roaylty_bp), 500);
+            assert_eq!(dynamic_royalty.get_royalty_bp(100, 0, false, metadat_roaylty_bp), 500);
+            assert_eq!(dynamic_royalty.get_royalty_bp(1000, 0, false, metadat_roaylty_bp), 2000);
+            assert_eq!(dynamic_royalty.get_royalty_bp(10000, 0, false, metadat_roaylty_bp), 2000);
+            assert_eq!(dynamic_royalty.get_royalty_bp(500, 0, false, metadat_roaylty_bp), 1166);
         }
 
         // override royalty_bp
@@ -260,18 +601,15 @@ mod tests {
                 kind: DYNAMIC_ROYALTY_KIND_PRICE_LINEAR,
                 override_royalty_bp: Some(0),
                 kind_price_linear: Some(price_linear),
-                _reserved_0: [0; 32],
-                _reserved_1: [0; 32],
-                _reserved_2: [0; 32],
-                _reserved_3: [0; 32],
+                ..Default::default()
             };
             let metadat_roaylty_bp = 1000; // not used
 
-            assert_eq!(dynamic_royalty.get_royalty_bp(0, metadat_roaylty_bp), 0);
-            assert_eq!(dynamic_royalty.get_royalty_bp(100, metadat_roaylty_bp), 0);
-            assert_eq!(dynamic_royalty.get_royalty_bp(1000, metadat_roaylty_bp), 0);
-            assert_eq!(dynamic_royalty.get_royalty_bp(10000, metadat_roaylty_bp), 0);
-            assert_eq!(dynamic_royalty.get_royalty_bp(500, metadat_roaylty_bp), 0);
+            assert_eq!(dynamic_royalty.get_royalty_bp(0, 0, false, metadat_roaylty_bp), 0);
+            assert_eq!(dynamic_royalty.get_royalty_bp(100, 0, false, metadat_roaylty_bp), 0);
+            assert_eq!(dynamic_royalty.get_royalty_bp(1000, 0, false, metadat_roaylty_bp), 0);
+            assert_eq!(dynamic_royalty.get_royalty_bp(10000, 0, false, metadat_roaylty_bp), 0);
+            assert_eq!(dynamic_royalty.get_royalty_bp(500, 0, false, metadat_roaylty_bp), 0);
 
             // with normal override
             let price_linear = DynamicRoyaltyPriceLinear {
@@ -286,18 +624,277 @@ mod tests {
                 kind: DYNAMIC_ROYALTY_KIND_PRICE_LINEAR,
                 override_royalty_bp: Some(2000),
                 kind_price_linear: Some(price_linear),
-                _reserved_0: [0; 32],
-                _reserved_1: [0; 32],
-                _reserved_2: [0; 32],
-                _reserved_3: [0; 32],
+                ..Default::default()
             };
             let metadat_roaylty_bp = 1000; // not used
 
-            assert_eq!(dynamic_royalty.get_royalty_bp(0, metadat_roaylty_bp), 1000);
-            assert_eq!(dynamic_royalty.get_royalty_bp(100, metadat_roaylty_bp), 1000);
-            assert_eq!(dynamic_royalty.get_royalty_bp(1000, metadat_roaylty_bp), 4000);
-            assert_eq!(dynamic_royalty.get_royalty_bp(10000, metadat_roaylty_bp), 4000);
-            assert_eq!(dynamic_royalty.get_royalty_bp(500, metadat_roaylty_bp), 2333);
+            assert_eq!(dynamic_royalty.get_royalty_bp(0, 0, false, metadat_roaylty_bp), 1000);
+            assert_eq!(dynamic_royalty.get_royalty_bp(100, 0, false, metadat_roaylty_bp), 1000);
+            assert_eq!(dynamic_royalty.get_royalty_bp(1000, 0, false, metadat_roaylty_bp), 4000);
+            assert_eq!(dynamic_royalty.get_royalty_bp(10000, 0, false, metadat_roaylty_bp), 4000);
+            assert_eq!(dynamic_royalty.get_royalty_bp(500, 0, false, metadat_roaylty_bp), 2333);
         }
     }
+
+    #[test]
+    fn test_price_piecewise_linear_function() {
+        let mut points = [DynamicRoyaltyPricePoint::default(); PRICE_PIECEWISE_LINEAR_MAX_POINTS];
+        points[0] = DynamicRoyaltyPricePoint {
+            price: 100,
+            multiplier_bp: 10000, // 100% until 100
+        };
+        points[1] = DynamicRoyaltyPricePoint {
+            price: 1000,
+            multiplier_bp: 5000, // down to 50% at 1000
+        };
+        points[2] = DynamicRoyaltyPricePoint {
+            price: 2000,
+            multiplier_bp: 5000, // flat 50% between 1000 and 2000
+        };
+        points[3] = DynamicRoyaltyPricePoint {
+            price: 3000,
+            multiplier_bp: 10000, // back up to 100% at 3000
+        };
+        let piecewise_linear = DynamicRoyaltyPricePiecewiseLinear { num_points: 4, points };
+        assert!(piecewise_linear.valid().is_ok());
+
+        let metadat_roaylty_bp = 1000;
+        assert_eq!(piecewise_linear.get_royalty_bp(0, metadat_roaylty_bp).unwrap(), 1000);
+        assert_eq!(piecewise_linear.get_royalty_bp(100, metadat_roaylty_bp).unwrap(), 1000);
+        assert_eq!(piecewise_linear.get_royalty_bp(550, metadat_roaylty_bp).unwrap(), 750);
+        assert_eq!(piecewise_linear.get_royalty_bp(1000, metadat_roaylty_bp).unwrap(), 500);
+        assert_eq!(piecewise_linear.get_royalty_bp(1500, metadat_roaylty_bp).unwrap(), 500);
+        assert_eq!(piecewise_linear.get_royalty_bp(2500, metadat_roaylty_bp).unwrap(), 750);
+        assert_eq!(piecewise_linear.get_royalty_bp(3000, metadat_roaylty_bp).unwrap(), 1000);
+        assert_eq!(piecewise_linear.get_royalty_bp(u64::MAX, metadat_roaylty_bp).unwrap(), 1000);
+
+        let dynamic_royalty = DynamicRoyalty {
+            version: 1,
+            kind: DYNAMIC_ROYALTY_KIND_PRICE_PIECEWISE_LINEAR,
+            override_royalty_bp: None,
+            kind_price_piecewise_linear: Some(piecewise_linear.clone()),
+            ..Default::default()
+        };
+        assert!(dynamic_royalty.valid().is_ok());
+        assert_eq!(dynamic_royalty.get_royalty_bp(550, 0, false, metadat_roaylty_bp), 750);
+
+        // must be set for the kind
+        let dynamic_royalty = DynamicRoyalty {
+            version: 1,
+            kind: DYNAMIC_ROYALTY_KIND_PRICE_PIECEWISE_LINEAR,
+            kind_price_piecewise_linear: None,
+            ..Default::default()
+        };
+        assert!(dynamic_royalty.valid().is_err());
+
+        // not monotonic
+        let mut invalid = piecewise_linear.clone();
+        invalid.points[2].price = 1000;
+        assert!(invalid.valid().is_err());
+
+        // too few or too many points
+        let mut invalid = piecewise_linear.clone();
+        invalid.num_points = 1;
+        assert!(invalid.valid().is_err());
+        invalid.num_points = PRICE_PIECEWISE_LINEAR_MAX_POINTS as u8 + 1;
+        assert!(invalid.valid().is_err());
+    }
+
+    #[test]
+    fn test_holding_time_linear_function() {
+        let holding_time_linear = DynamicRoyaltyHoldingTimeLinear {
+            start_duration: 0,
+            end_duration: 30 * 86400,
+            start_multiplier_bp: 20000, // 200% when flipped right away
+            end_multiplier_bp: 5000,    // 50% after holding for 30 days
+        };
+        assert!(holding_time_linear.valid().is_ok());
+
+        let dynamic_royalty = DynamicRoyalty {
+            version: 1,
+            kind: DYNAMIC_ROYALTY_KIND_HOLDING_TIME_LINEAR,
+            override_royalty_bp: None,
+            kind_holding_time_linear: Some(holding_time_linear),
+            ..Default::default()
+        };
+        assert!(dynamic_royalty.valid().is_ok());
+
+        let metadat_roaylty_bp = 500;
+        let price = 1000; // price is not used for this kind
+        assert_eq!(dynamic_royalty.get_royalty_bp(price, -100, false, metadat_roaylty_bp), 1000);
+        assert_eq!(dynamic_royalty.get_royalty_bp(price, 0, false, metadat_roaylty_bp), 1000);
+        assert_eq!(dynamic_royalty.get_royalty_bp(price, 15 * 86400, false, metadat_roaylty_bp), 625);
+        assert_eq!(dynamic_royalty.get_royalty_bp(price * 1000, 15 * 86400, false, metadat_roaylty_bp), 625);
+        assert_eq!(dynamic_royalty.get_royalty_bp(price, 30 * 86400, false, metadat_roaylty_bp), 250);
+        assert_eq!(dynamic_royalty.get_royalty_bp(price, 365 * 86400, false, metadat_roaylty_bp), 250);
+
+        // invalid durations
+        let mut invalid = dynamic_royalty.clone();
+        invalid.kind_holding_time_linear.as_mut().unwrap().start_duration = -1;
+        assert!(invalid.valid().is_err());
+        let mut invalid = dynamic_royalty.clone();
+        invalid.kind_holding_time_linear.as_mut().unwrap().end_duration = -1;
+        assert!(invalid.valid().is_err());
+        let mut invalid = dynamic_royalty;
+        invalid.kind_holding_time_linear = None;
+        assert!(invalid.valid().is_err());
+    }
+
+    #[test]
+    fn test_holder_discount_function() {
+        let dynamic_royalty = DynamicRoyalty {
+            version: 1,
+            kind: DYNAMIC_ROYALTY_KIND_HOLDER_DISCOUNT,
+            override_royalty_bp: None,
+            kind_holder_discount: Some(DynamicRoyaltyHolderDiscount {
+                collection_mint: Pubkey::new_unique(),
+                discount_bp: 2500, // 25% off
+            }),
+            ..Default::default()
+        };
+        assert!(dynamic_royalty.valid().is_ok());
+
+        let metadat_roaylty_bp = 1000;
+        assert_eq!(dynamic_royalty.get_royalty_bp(100, 0, false, metadat_roaylty_bp), 1000);
+        assert_eq!(dynamic_royalty.get_royalty_bp(100, 0, true, metadat_roaylty_bp), 750);
+
+        // no pass accounts, no discount
+        assert!(!dynamic_royalty.is_pass_holder(&Pubkey::new_unique(), &[]).unwrap());
+
+        let mut free_for_holders = dynamic_royalty.clone();
+        free_for_holders.kind_holder_discount.as_mut().unwrap().discount_bp = 10000;
+        assert!(free_for_holders.valid().is_ok());
+        assert_eq!(free_for_holders.get_royalty_bp(100, 0, true, metadat_roaylty_bp), 0);
+
+        let mut invalid = dynamic_royalty.clone();
+        invalid.kind_holder_discount.as_mut().unwrap().discount_bp = 10001;
+        assert!(invalid.valid().is_err());
+        let mut invalid = dynamic_royalty;
+        invalid.kind_holder_discount = None;
+        assert!(invalid.valid().is_err());
+    }
+
+    #[test]
+    fn test_royalty_bounds() {
+        let dynamic_royalty = DynamicRoyalty {
+            version: 1,
+            kind: DYNAMIC_ROYALTY_KIND_PRICE_LINEAR,
+            kind_price_linear: Some(DynamicRoyaltyPriceLinear {
+                price_mint: None,
+                start_price: 100,
+                end_price: 1000,
+                start_multiplier_bp: 0,   // start from 0%
+                end_multiplier_bp: 30000, // end at 300%
+            }),
+            min_royalty_bp: Some(100),
+            max_royalty_bp: Some(2000),
+            min_royalty_amount: Some(50),
+            ..Default::default()
+        };
+        assert!(dynamic_royalty.valid().is_ok());
+
+        let metadat_roaylty_bp = 1000;
+        assert_eq!(dynamic_royalty.get_royalty_bp(100, 0, false, metadat_roaylty_bp), 100);
+        assert_eq!(dynamic_royalty.get_royalty_bp(400, 0, false, metadat_roaylty_bp), 1000);
+        assert_eq!(dynamic_royalty.get_royalty_bp(1000, 0, false, metadat_roaylty_bp), 2000);
+
+        assert_eq!(dynamic_royalty.get_royalty_amount(LAMPORTS_PER_SOL, 100).unwrap(), LAMPORTS_PER_SOL / 100);
+        assert_eq!(dynamic_royalty.get_royalty_amount(1000, 100).unwrap(), 50);
+        assert_eq!(dynamic_royalty.get_royalty_amount(10, 100).unwrap(), 10); // never more than the price
+
+        let mut invalid = dynamic_royalty.clone();
+        invalid.min_royalty_bp = Some(3000);
+        assert!(invalid.valid().is_err());
+        let mut invalid = dynamic_royalty;
+        invalid.max_royalty_bp = Some(10001);
+        assert!(invalid.valid().is_err());
+    }
+
+    #[test]
+    fn test_dynamic_royalty_layout_backward_compatible() {
+        // version, kind, override_royalty_bp: None, kind_price_linear: None, and the 128 reserved bytes of the old layout
+        let mut data = vec![1, DYNAMIC_ROYALTY_KIND_PRICE_LINEAR, 0, 0];
+        data.extend_from_slice(&[0; 128]);
+        let mut buf: &[u8] = &data;
+        let dynamic_royalty: DynamicRoyalty = AnchorDeserialize::deserialize(&mut buf).unwrap();
+        assert!(buf.is_empty());
+        assert!(dynamic_royalty.kind_price_piecewise_linear.is_none());
+        assert_eq!(dynamic_royalty.try_to_vec().unwrap(), data);
+    }
+
+    #[test]
+    fn test_royalty_payment_introspection() {
+        let from = Pubkey::new_unique();
+        let to = Pubkey::new_unique();
+        let ix = solana_program::system_instruction::transfer(&from, &to, LAMPORTS_PER_SOL);
+        assert_eq!(parse_system_transfer(&ix), Some((from, to, LAMPORTS_PER_SOL)));
+
+        // only the transfers from the senders to the recipients are payments
+        let other = Pubkey::new_unique();
+        let mut paid = vec![0u64; 2];
+        assert!(add_paid_lamports(&mut paid, &ix, &[from], &[other, to]).unwrap());
+        assert!(add_paid_lamports(&mut paid, &ix, &[from], &[other, to]).unwrap());
+        assert_eq!(paid, vec![0, 2 * LAMPORTS_PER_SOL]);
+        assert!(!add_paid_lamports(&mut paid, &ix, &[other], &[other, to]).unwrap());
+        assert!(!add_paid_lamports(&mut paid, &ix, &[from], &[other]).unwrap());
+        assert_eq!(paid, vec![0, 2 * LAMPORTS_PER_SOL]);
+
+        let ix = solana_program::system_instruction::assign(&from, &to);
+        assert_eq!(parse_system_transfer(&ix), None);
+        assert!(!add_paid_lamports(&mut paid, &ix, &[from], &[to]).unwrap());
+        let ix = spl_memo::build_memo(b"{}", &[]);
+        assert_eq!(parse_system_transfer(&ix), None);
+
+        assert_eq!(RoyaltyPaymentMemo::parse(Some(&r#"{"price":1000}"#.to_string())).unwrap().price, 1000);
+        assert!(RoyaltyPaymentMemo::parse(Some(&"1000".to_string())).is_err());
+        assert!(RoyaltyPaymentMemo::parse(None).is_err());
+    }
+
+    #[test]
+    fn test_royalty_payment_mint() {
+        let price_mint = Pubkey::new_unique();
+        assert!(check_payment_mint(None, None).is_ok());
+        assert!(check_payment_mint(Some(price_mint), Some(price_mint)).is_ok());
+        // the payment_mint is missing or another mint, or passed for a royalty in SOL
+        assert!(check_payment_mint(Some(price_mint), None).is_err());
+        assert!(check_payment_mint(Some(price_mint), Some(Pubkey::new_unique())).is_err());
+        assert!(check_payment_mint(None, Some(price_mint)).is_err());
+
+        let recipient = Pubkey::new_unique();
+        let token_account = |owner: Pubkey, mint: Pubkey| {
+            let mut data = vec![0; spl_token::state::Account::LEN];
+            spl_token::state::Account {
+                mint,
+                owner,
+                state: spl_token::state::AccountState::Initialized,
+                ..Default::default()
+            }
+            .pack_into_slice(&mut data);
+            data
+        };
+        let key = Pubkey::new_unique();
+        let check = |data: &mut Vec<u8>, program_id: &Pubkey| {
+            let mut lamports = 0;
+            let account = AccountInfo::new(&key, false, true, &mut lamports, data, program_id, false, 0);
+            check_recipient_token_account(&account, &recipient, &price_mint)
+        };
+        assert!(check(&mut token_account(recipient, price_mint), &spl_token::ID).is_ok());
+        assert!(check(&mut token_account(recipient, price_mint), &Pubkey::new_unique()).is_err()); // not a token account
+        assert!(check(&mut token_account(Pubkey::new_unique(), price_mint), &spl_token::ID).is_err());
+        assert!(check(&mut token_account(recipient, Pubkey::new_unique()), &spl_token::ID).is_err());
+    }
+
+    #[test]
+    fn test_royalty_amount_split() {
+        assert_eq!(get_royalty_amount(LAMPORTS_PER_SOL, 500).unwrap(), LAMPORTS_PER_SOL / 20);
+        assert_eq!(get_royalty_amount(LAMPORTS_PER_SOL, 0).unwrap(), 0);
+        assert_eq!(get_royalty_amount(999, 1).unwrap(), 0);
+        assert_eq!(get_royalty_amount(u64::MAX, 10000).unwrap(), u64::MAX);
+
+        assert_eq!(split_royalty_amount(1000, &[100]).unwrap(), vec![1000]);
+        assert_eq!(split_royalty_amount(1000, &[50, 50]).unwrap(), vec![500, 500]);
+        assert_eq!(split_royalty_amount(1000, &[0, 33, 67]).unwrap(), vec![0, 330, 670]);
+        assert_eq!(split_royalty_amount(10, &[33, 33, 34]).unwrap(), vec![3, 3, 3]); // dust stays with the payer
+        assert_eq!(split_royalty_amount(1000, &[]).unwrap(), Vec::<u64>::new());
+    }
 }
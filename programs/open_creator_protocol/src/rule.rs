@@ -0,0 +1,846 @@
+use crate::action::ActionCtx;
+use crate::errors::{policy_error, OCPErrorCode, POLICY_ERROR_CODE_MAX};
+use crate::events::PolicyDenied;
+use crate::merkle::{verify_merkle_proofs, MerkleNode};
+use crate::state::ListAccount;
+use anchor_lang::prelude::*;
+use json_rules_engine_fork::{ConditionResult, Constraint, Status};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+// the json_rule format of json_rules_engine_fork, extended with the operators that json_rules_engine_fork doesn't
+// know about. A json_rule with the extended operators can only be evaluated once compiled
+#[derive(Clone, Debug, Deserialize)]
+pub struct JsonRule {
+    pub conditions: JsonCondition,
+    pub events: Vec<serde_json::Value>,
+}
+
+// the events that the program executes, in the json_rules_engine_fork event format {"type":"...","params":{...}}.
+// The events of the other types are left to the off-chain consumers
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "type", content = "params")]
+pub enum JsonRuleEvent {
+    EmitError { message: String },
+    RequireMemo {},
+    ChargeFee { lamports: u64, recipient: String },
+    IncrementCounter { index: u8 },
+}
+
+// every condition can have an error, the reason given to the clients when the condition is not met
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum JsonCondition {
+    And {
+        and: Vec<JsonCondition>,
+        error: Option<ConditionError>,
+    },
+    Or {
+        or: Vec<JsonCondition>,
+        error: Option<ConditionError>,
+    },
+    Not {
+        not: Box<JsonCondition>,
+        error: Option<ConditionError>,
+    },
+    AtLeast {
+        should_minimum_meet: usize,
+        conditions: Vec<JsonCondition>,
+        error: Option<ConditionError>,
+    },
+    ExtendedCondition {
+        field: String,
+        #[serde(flatten)]
+        constraint: ExtendedConstraint,
+        error: Option<ConditionError>,
+    },
+    Condition {
+        field: String,
+        #[serde(flatten)]
+        constraint: Constraint,
+        path: Option<String>,
+        error: Option<ConditionError>,
+    },
+}
+
+// the code is in [0, POLICY_ERROR_CODE_MAX], returned as the error POLICY_ERROR_CODE_OFFSET + code
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, AnchorSerialize, AnchorDeserialize)]
+pub struct ConditionError {
+    pub code: u16,
+    pub message: String,
+}
+
+// the value of the list operators is the address of a ListAccount, passed in the remaining accounts of the action.
+// The value of the merkle operators is the base58 Merkle root, and the proofs come with the action, see merkle.rs
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "operator", content = "value")]
+pub enum ExtendedConstraint {
+    InList(String),          // e.g. "to" is in the list
+    NotInList(String),       // e.g. "to" is not in the list
+    AllInList(String),       // e.g. all of the "program_ids" are in the list
+    NoneInList(String),      // e.g. none of the "program_ids" is in the list
+    InMerkleTree(String),    // e.g. "to" is proven to be in the Merkle tree
+    AllInMerkleTree(String), // e.g. all of the "program_ids" are proven to be in the Merkle tree
+}
+
+impl JsonRule {
+    pub const EVENT_TYPES: [&'static str; 4] = ["emit_error", "require_memo", "charge_fee", "increment_counter"];
+
+    pub fn has_extended_operators(&self) -> bool {
+        self.conditions.has_extended_operators()
+    }
+
+    // None when an event of the known types is invalid
+    pub fn compile_events(&self) -> Option<Vec<RuleEvent>> {
+        self.events
+            .iter()
+            .filter(|x| x.get("type").and_then(|x| x.as_str()).is_some_and(|x| JsonRule::EVENT_TYPES.contains(&x)))
+            .map(|x| RuleEvent::compile(&serde_json::from_value::<JsonRuleEvent>(x.clone()).ok()?))
+            .collect()
+    }
+}
+
+impl JsonCondition {
+    // the conditions with an error are extended as well, json_rules_engine_fork ignores the errors
+    pub fn has_extended_operators(&self) -> bool {
+        if self.error().is_some() {
+            return true;
+        }
+        match self {
+            JsonCondition::And { and: conditions, .. } | JsonCondition::Or { or: conditions, .. } | JsonCondition::AtLeast { conditions, .. } => {
+                conditions.iter().any(|c| c.has_extended_operators())
+            }
+            JsonCondition::Not { not, .. } => not.has_extended_operators(),
+            JsonCondition::ExtendedCondition { .. } => true,
+            JsonCondition::Condition { .. } => false,
+        }
+    }
+
+    pub fn error(&self) -> Option<&ConditionError> {
+        match self {
+            JsonCondition::And { error, .. }
+            | JsonCondition::Or { error, .. }
+            | JsonCondition::Not { error, .. }
+            | JsonCondition::AtLeast { error, .. }
+            | JsonCondition::ExtendedCondition { error, .. }
+            | JsonCondition::Condition { error, .. } => error.as_ref(),
+        }
+    }
+}
+
+// the json_rule is compiled into this Borsh encoded AST at init_policy/update_policy time, so that the policy
+// evaluation doesn't need to parse the json_rule or serialize the ActionCtx into a serde_json::Value on every action.
+// The evaluation results are the same as json_rules_engine_fork, and a json_rule that cannot be compiled
+// (e.g. with float operators or unknown fields) is still evaluated with json_rules_engine_fork.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, AnchorSerialize, AnchorDeserialize)]
+pub struct CompiledRule {
+    pub conditions: CompiledCondition,
+    pub events: Vec<RuleEvent>,
+}
+
+// the side effects of a rule. emit_error fires when the rule fails, the others fire when the rule matches
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, AnchorSerialize, AnchorDeserialize)]
+pub enum RuleEvent {
+    EmitError(String),      // the message is logged before the action fails
+    RequireMemo,            // the action needs a memo
+    ChargeFee(u64, Pubkey), // the payer (or from, without a payer) pays the lamports to the recipient
+    IncrementCounter(u8),   // the RuleCounter of the policy at the index is incremented
+}
+
+impl RuleEvent {
+    pub fn compile(event: &JsonRuleEvent) -> Option<Self> {
+        let compiled = match event {
+            JsonRuleEvent::EmitError { message } => Self::EmitError(message.clone()),
+            JsonRuleEvent::RequireMemo {} => Self::RequireMemo,
+            JsonRuleEvent::ChargeFee { lamports, recipient } => Self::ChargeFee(*lamports, Pubkey::from_str(recipient).ok()?),
+            JsonRuleEvent::IncrementCounter { index } => Self::IncrementCounter(*index),
+        };
+        Some(compiled)
+    }
+}
+
+// the events to apply when the rule matches. When it doesn't, the emit_error messages are logged, and the error
+// of the failed condition if any is emitted in a PolicyDenied event and returned as a policy error
+pub fn get_matched_events(status: Status, events: &[RuleEvent], error: Option<&ConditionError>, ctx: &ActionCtx) -> Result<Vec<RuleEvent>> {
+    if status != Status::Met {
+        for event in events {
+            if let RuleEvent::EmitError(message) = event {
+                msg!("{}", message);
+            }
+        }
+        return match error {
+            Some(error) => {
+                emit!(PolicyDenied {
+                    mint: Pubkey::from_str(&ctx.mint).unwrap_or_default(),
+                    action: ctx.action.clone(),
+                    code: error.code,
+                    message: error.message.clone(),
+                });
+                Err(policy_error(error.code, &error.message))
+            }
+            None => Err(OCPErrorCode::InvalidPolicyEvaluation.into()),
+        };
+    }
+    if events.contains(&RuleEvent::RequireMemo) && ctx.last_memo_data.is_none() {
+        msg!("a memo is required");
+        return Err(OCPErrorCode::MemoRequired.into());
+    }
+    Ok(events.to_vec())
+}
+
+// AnchorSerialize and AnchorDeserialize are implemented by hand, the derive macros can't handle the recursive type
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum CompiledCondition {
+    And(Vec<CompiledCondition>),
+    Or(Vec<CompiledCondition>),
+    Not(Box<CompiledCondition>),
+    AtLeast(u32, Vec<CompiledCondition>),
+    Condition(Fact, CompiledConstraint),
+    WithError(ConditionError, Box<CompiledCondition>),
+}
+
+pub const TRACE_STATUS_MET: u8 = 0;
+pub const TRACE_STATUS_NOT_MET: u8 = 1;
+pub const TRACE_STATUS_UNKNOWN: u8 = 2;
+pub const TRACE_STR_MAX_LEN: usize = 64; // the names and the fact values are cut to fit in the return data
+
+// one condition of an evaluation trace. The conditions are listed depth first, with the depth of the nesting
+#[derive(Clone, Debug, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub struct ConditionTrace {
+    pub depth: u8,
+    pub name: String,
+    pub fact: Option<String>, // the value the condition looked at, None for the and/or/not/at least conditions
+    pub status: u8,
+}
+
+// the fields of the ActionCtx that a condition can read, named after their json pointer in the ActionCtx
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize, AnchorSerialize, AnchorDeserialize)]
+pub enum Fact {
+    Action,
+    ProgramIds,
+    Mint,
+    Payer,
+    From,
+    To,
+    LastMemoSigner,
+    LastMemoData,
+    MintStateVersion,
+    MintStatePolicy,
+    MintStateLockedBy,
+    MintStateLastApprovedAt,
+    MintStateLastTransferredAt,
+    MintStateTransferredCount,
+    MintStateDerivedCooldown,
+    MintStateDerivedDatetimeUtcTimestamp,
+    MintStateDerivedDatetimeUtcHour,
+    MintAccountMintAuthority,
+    MintAccountSupply,
+    MintAccountDecimals,
+    MintAccountIsInitialized,
+    MintAccountFreezeAuthority,
+    MetadataName,
+    MetadataSymbol,
+    MetadataUri,
+    MetadataSellerFeeBasisPoints,
+    MetadataUpdateAuthority,
+    PolicyFrozen,
+    PolicyAuthorityFrozen,
+}
+
+// same as json_rules_engine_fork::Constraint without the float operators, and with the ExtendedConstraint
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, AnchorSerialize, AnchorDeserialize)]
+pub enum CompiledConstraint {
+    StringEquals(String),
+    StringNotEquals(String),
+    StringContains(String),
+    StringContainsAll(Vec<String>),
+    StringContainsAny(Vec<String>),
+    StringDoesNotContain(String),
+    StringDoesNotContainAny(Vec<String>),
+    StringIn(Vec<String>),
+    StringNotIn(Vec<String>),
+    StringIsSubset(Vec<String>),
+    StringIsSubstring(String),
+    StringHasSubstring(String),
+    IntEquals(i64),
+    IntNotEquals(i64),
+    IntContains(i64),
+    IntContainsAll(Vec<i64>),
+    IntContainsAny(Vec<i64>),
+    IntDoesNotContain(i64),
+    IntDoesNotContainAny(Vec<i64>),
+    IntIn(Vec<i64>),
+    IntNotIn(Vec<i64>),
+    IntInRange(i64, i64),
+    IntNotInRange(i64, i64),
+    IntLessThan(i64),
+    IntLessThanInclusive(i64),
+    IntGreaterThan(i64),
+    IntGreaterThanInclusive(i64),
+    BoolEquals(bool),
+    InList(Pubkey),
+    NotInList(Pubkey),
+    AllInList(Pubkey),
+    NoneInList(Pubkey),
+    InMerkleTree(MerkleNode),
+    AllInMerkleTree(MerkleNode),
+}
+
+// the value of a fact, as the json_rules_engine_fork would see it in the serde_json::Value
+pub enum FactValue<'a> {
+    Str(&'a str),
+    StrArray(&'a [String]),
+    Int(i64),
+    Bool(bool),
+    Null, // None, or a number that doesn't fit in i64. No constraint is met with it
+}
+
+impl CompiledRule {
+    pub fn compile(rule: &JsonRule) -> Option<Self> {
+        Some(Self {
+            conditions: CompiledCondition::compile(&rule.conditions)?,
+            events: rule.compile_events()?,
+        })
+    }
+
+    // accounts are where the ListAccounts are looked up, usually the remaining accounts
+    pub fn evaluate(&self, ctx: &ActionCtx, accounts: &[AccountInfo]) -> Status {
+        self.conditions.evaluate(ctx, accounts)
+    }
+
+    pub fn trace(&self, ctx: &ActionCtx, accounts: &[AccountInfo]) -> (Status, Vec<ConditionTrace>) {
+        let mut trace = vec![];
+        let status = self.conditions.trace(ctx, accounts, 0, &mut trace);
+        (status, trace)
+    }
+}
+
+impl CompiledCondition {
+    pub fn compile(condition: &JsonCondition) -> Option<Self> {
+        let compiled = match condition {
+            JsonCondition::And { and, .. } => Some(Self::And(and.iter().map(Self::compile).collect::<Option<Vec<_>>>()?)),
+            JsonCondition::Or { or, .. } => Some(Self::Or(or.iter().map(Self::compile).collect::<Option<Vec<_>>>()?)),
+            JsonCondition::Not { not, .. } => Some(Self::Not(Box::new(Self::compile(not)?))),
+            JsonCondition::AtLeast {
+                should_minimum_meet,
+                conditions,
+                ..
+            } => Some(Self::AtLeast(
+                u32::try_from(*should_minimum_meet).ok()?,
+                conditions.iter().map(Self::compile).collect::<Option<Vec<_>>>()?,
+            )),
+            JsonCondition::ExtendedCondition { field, constraint, .. } => Some(Self::Condition(
+                Fact::from_field(field)?,
+                CompiledConstraint::compile_extended(constraint)?,
+            )),
+            JsonCondition::Condition { field, constraint, path, .. } => {
+                if path.is_some() {
+                    return None;
+                }
+                Some(Self::Condition(Fact::from_field(field)?, CompiledConstraint::compile(constraint)?))
+            }
+        }?;
+        match condition.error() {
+            Some(error) if error.code <= POLICY_ERROR_CODE_MAX => Some(Self::WithError(error.clone(), Box::new(compiled))),
+            Some(_) => None,
+            None => Some(compiled),
+        }
+    }
+
+    // the same status as json_rules_engine_fork::Condition::check_value
+    pub fn evaluate(&self, ctx: &ActionCtx, accounts: &[AccountInfo]) -> Status {
+        match self {
+            Self::And(conditions) => conditions.iter().fold(Status::Met, |status, c| status & c.evaluate(ctx, accounts)),
+            Self::Or(conditions) => conditions.iter().fold(Status::NotMet, |status, c| status | c.evaluate(ctx, accounts)),
+            Self::Not(condition) => !condition.evaluate(ctx, accounts),
+            Self::AtLeast(should_minimum_meet, conditions) => {
+                let met_count = conditions.iter().filter(|c| c.evaluate(ctx, accounts) == Status::Met).count();
+                match met_count >= *should_minimum_meet as usize {
+                    true => Status::Met,
+                    false => Status::NotMet,
+                }
+            }
+            Self::Condition(fact, constraint) => match fact.get(ctx) {
+                Some(value) => constraint.check_value(&value, ctx, accounts),
+                None => Status::Unknown, // same as a missing json pointer
+            },
+            Self::WithError(_, condition) => condition.evaluate(ctx, accounts),
+        }
+    }
+
+    // the ListAccounts that the list operators look up
+    pub fn lists(&self, lists: &mut Vec<Pubkey>) {
+        match self {
+            Self::And(conditions) | Self::Or(conditions) | Self::AtLeast(_, conditions) => conditions.iter().for_each(|c| c.lists(lists)),
+            Self::Not(condition) | Self::WithError(_, condition) => condition.lists(lists),
+            Self::Condition(_, constraint) => match constraint {
+                CompiledConstraint::InList(list)
+                | CompiledConstraint::NotInList(list)
+                | CompiledConstraint::AllInList(list)
+                | CompiledConstraint::NoneInList(list) => lists.push(*list),
+                _ => {}
+            },
+        }
+    }
+
+    // the error of the innermost condition that is not met, the conditions under a not are skipped
+    pub fn find_error(&self, ctx: &ActionCtx, accounts: &[AccountInfo]) -> Option<&ConditionError> {
+        match self {
+            Self::And(conditions) | Self::Or(conditions) | Self::AtLeast(_, conditions) => conditions
+                .iter()
+                .filter(|c| c.evaluate(ctx, accounts) != Status::Met)
+                .find_map(|c| c.find_error(ctx, accounts)),
+            Self::Not(_) | Self::Condition(..) => None,
+            Self::WithError(error, condition) => match condition.evaluate(ctx, accounts) {
+                Status::Met => None,
+                _ => condition.find_error(ctx, accounts).or(Some(error)),
+            },
+        }
+    }
+
+    // same as evaluate, and records every condition into the trace
+    pub fn trace(&self, ctx: &ActionCtx, accounts: &[AccountInfo], depth: u8, trace: &mut Vec<ConditionTrace>) -> Status {
+        if let Self::WithError(_, condition) = self {
+            return condition.trace(ctx, accounts, depth, trace);
+        }
+        let index = trace.len();
+        let name = match self {
+            Self::Condition(fact, constraint) => format!("{} {:?}", fact.to_field(), constraint),
+            _ => self.name(),
+        };
+        trace.push(ConditionTrace {
+            depth,
+            name: truncate(&name),
+            fact: None,
+            status: TRACE_STATUS_UNKNOWN,
+        });
+        let depth = depth.saturating_add(1);
+        let status = match self {
+            Self::And(conditions) => conditions
+                .iter()
+                .fold(Status::Met, |status, c| status & c.trace(ctx, accounts, depth, trace)),
+            Self::Or(conditions) => conditions
+                .iter()
+                .fold(Status::NotMet, |status, c| status | c.trace(ctx, accounts, depth, trace)),
+            Self::Not(condition) => !condition.trace(ctx, accounts, depth, trace),
+            Self::AtLeast(should_minimum_meet, conditions) => {
+                let met_count = conditions.iter().filter(|c| c.trace(ctx, accounts, depth, trace) == Status::Met).count();
+                to_status(met_count >= *should_minimum_meet as usize)
+            }
+            Self::Condition(fact, constraint) => match fact.get(ctx) {
+                Some(value) => {
+                    trace[index].fact = Some(truncate(&value.to_string()));
+                    constraint.check_value(&value, ctx, accounts)
+                }
+                None => Status::Unknown,
+            },
+            Self::WithError(..) => unreachable!(),
+        };
+        trace[index].status = to_trace_status(status);
+        status
+    }
+
+    // same as the json_rules_engine_fork::ConditionResult name, used in the logs
+    pub fn name(&self) -> String {
+        match self {
+            Self::And(_) => "And".to_string(),
+            Self::Or(_) => "Or".to_string(),
+            Self::Not(_) => "Not".to_string(),
+            Self::AtLeast(should_minimum_meet, conditions) => format!("At least meet {} of {}", should_minimum_meet, conditions.len()),
+            Self::Condition(fact, _) => fact.to_field().to_string(),
+            Self::WithError(_, condition) => condition.name(),
+        }
+    }
+}
+
+impl AnchorSerialize for CompiledCondition {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        match self {
+            Self::And(conditions) => {
+                AnchorSerialize::serialize(&0u8, writer)?;
+                AnchorSerialize::serialize(conditions, writer)
+            }
+            Self::Or(conditions) => {
+                AnchorSerialize::serialize(&1u8, writer)?;
+                AnchorSerialize::serialize(conditions, writer)
+            }
+            Self::Not(condition) => {
+                AnchorSerialize::serialize(&2u8, writer)?;
+                AnchorSerialize::serialize(condition, writer)
+            }
+            Self::AtLeast(should_minimum_meet, conditions) => {
+                AnchorSerialize::serialize(&3u8, writer)?;
+                AnchorSerialize::serialize(should_minimum_meet, writer)?;
+                AnchorSerialize::serialize(conditions, writer)
+            }
+            Self::Condition(fact, constraint) => {
+                AnchorSerialize::serialize(&4u8, writer)?;
+                AnchorSerialize::serialize(fact, writer)?;
+                AnchorSerialize::serialize(constraint, writer)
+            }
+            Self::WithError(error, condition) => {
+                AnchorSerialize::serialize(&5u8, writer)?;
+                AnchorSerialize::serialize(error, writer)?;
+                AnchorSerialize::serialize(condition, writer)
+            }
+        }
+    }
+}
+
+impl AnchorDeserialize for CompiledCondition {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        match u8::deserialize_reader(reader)? {
+            0 => Ok(Self::And(Vec::deserialize_reader(reader)?)),
+            1 => Ok(Self::Or(Vec::deserialize_reader(reader)?)),
+            2 => Ok(Self::Not(Box::new(Self::deserialize_reader(reader)?))),
+            3 => Ok(Self::AtLeast(u32::deserialize_reader(reader)?, Vec::deserialize_reader(reader)?)),
+            4 => Ok(Self::Condition(
+                Fact::deserialize_reader(reader)?,
+                CompiledConstraint::deserialize_reader(reader)?,
+            )),
+            5 => Ok(Self::WithError(
+                ConditionError::deserialize_reader(reader)?,
+                Box::new(Self::deserialize_reader(reader)?),
+            )),
+            _ => Err(std::io::Error::new(std::io::ErrorKind::InvalidData, "invalid CompiledCondition")),
+        }
+    }
+}
+
+impl Fact {
+    const FIELDS: [(Fact, &'static str); 29] = [
+        (Fact::Action, "action"),
+        (Fact::ProgramIds, "program_ids"),
+        (Fact::Mint, "mint"),
+        (Fact::Payer, "payer"),
+        (Fact::From, "from"),
+        (Fact::To, "to"),
+        (Fact::LastMemoSigner, "last_memo_signer"),
+        (Fact::LastMemoData, "last_memo_data"),
+        (Fact::MintStateVersion, "mint_state/version"),
+        (Fact::MintStatePolicy, "mint_state/policy"),
+        (Fact::MintStateLockedBy, "mint_state/locked_by"),
+        (Fact::MintStateLastApprovedAt, "mint_state/last_approved_at"),
+        (Fact::MintStateLastTransferredAt, "mint_state/last_transferred_at"),
+        (Fact::MintStateTransferredCount, "mint_state/transferred_count"),
+        (Fact::MintStateDerivedCooldown, "mint_state/derived_cooldown"),
+        (Fact::MintStateDerivedDatetimeUtcTimestamp, "mint_state/derived_datetime/utc_timestamp"),
+        (Fact::MintStateDerivedDatetimeUtcHour, "mint_state/derived_datetime/utc_hour"),
+        (Fact::MintAccountMintAuthority, "mint_account/mint_authority"),
+        (Fact::MintAccountSupply, "mint_account/supply"),
+        (Fact::MintAccountDecimals, "mint_account/decimals"),
+        (Fact::MintAccountIsInitialized, "mint_account/is_initialized"),
+        (Fact::MintAccountFreezeAuthority, "mint_account/freeze_authority"),
+        (Fact::MetadataName, "metadata/name"),
+        (Fact::MetadataSymbol, "metadata/symbol"),
+        (Fact::MetadataUri, "metadata/uri"),
+        (Fact::MetadataSellerFeeBasisPoints, "metadata/seller_fee_basis_points"),
+        (Fact::MetadataUpdateAuthority, "metadata/update_authority"),
+        (Fact::PolicyFrozen, "policy/frozen"),
+        (Fact::PolicyAuthorityFrozen, "policy/authority_frozen"),
+    ];
+
+    pub fn from_field(field: &str) -> Option<Self> {
+        let field = field.strip_prefix('/').unwrap_or(field);
+        Fact::FIELDS.iter().find(|(_, name)| *name == field).map(|(fact, _)| *fact)
+    }
+
+    pub fn to_field(&self) -> &'static str {
+        Fact::FIELDS
+            .iter()
+            .find(|(fact, _)| fact == self)
+            .map(|(_, name)| *name)
+            .expect("every fact should have a field")
+    }
+
+    // None when the fact is missing, e.g. the metadata of an action without the metadata account
+    pub fn get<'a>(&self, ctx: &'a ActionCtx) -> Option<FactValue<'a>> {
+        let value = match self {
+            Fact::Action => FactValue::Str(&ctx.action),
+            Fact::ProgramIds => FactValue::StrArray(&ctx.program_ids),
+            Fact::Mint => FactValue::Str(&ctx.mint),
+            Fact::Payer => FactValue::from_option(&ctx.payer),
+            Fact::From => FactValue::from_option(&ctx.from),
+            Fact::To => FactValue::from_option(&ctx.to),
+            Fact::LastMemoSigner => FactValue::from_option(&ctx.last_memo_signer),
+            Fact::LastMemoData => FactValue::from_option(&ctx.last_memo_data),
+            Fact::MintStateVersion => FactValue::Int(ctx.mint_state.version.into()),
+            Fact::MintStatePolicy => FactValue::Str(&ctx.mint_state.policy),
+            Fact::MintStateLockedBy => FactValue::from_option(&ctx.mint_state.locked_by),
+            Fact::MintStateLastApprovedAt => FactValue::Int(ctx.mint_state.last_approved_at),
+            Fact::MintStateLastTransferredAt => FactValue::Int(ctx.mint_state.last_transferred_at),
+            Fact::MintStateTransferredCount => FactValue::Int(ctx.mint_state.transferred_count.into()),
+            Fact::MintStateDerivedCooldown => FactValue::Int(ctx.mint_state.derived_cooldown),
+            Fact::MintStateDerivedDatetimeUtcTimestamp => FactValue::Int(ctx.mint_state.derived_datetime.utc_timestamp),
+            Fact::MintStateDerivedDatetimeUtcHour => FactValue::Int(ctx.mint_state.derived_datetime.utc_hour.into()),
+            Fact::MintAccountMintAuthority => FactValue::from_option(&ctx.mint_account.as_ref()?.mint_authority),
+            Fact::MintAccountSupply => FactValue::from_u64(ctx.mint_account.as_ref()?.supply),
+            Fact::MintAccountDecimals => FactValue::Int(ctx.mint_account.as_ref()?.decimals.into()),
+            Fact::MintAccountIsInitialized => FactValue::Bool(ctx.mint_account.as_ref()?.is_initialized),
+            Fact::MintAccountFreezeAuthority => FactValue::from_option(&ctx.mint_account.as_ref()?.freeze_authority),
+            Fact::MetadataName => FactValue::Str(&ctx.metadata.as_ref()?.name),
+            Fact::MetadataSymbol => FactValue::Str(&ctx.metadata.as_ref()?.symbol),
+            Fact::MetadataUri => FactValue::Str(&ctx.metadata.as_ref()?.uri),
+            Fact::MetadataSellerFeeBasisPoints => FactValue::Int(ctx.metadata.as_ref()?.seller_fee_basis_points.into()),
+            Fact::MetadataUpdateAuthority => FactValue::Str(&ctx.metadata.as_ref()?.update_authority),
+            Fact::PolicyFrozen => FactValue::Bool(ctx.policy.as_ref()?.frozen),
+            Fact::PolicyAuthorityFrozen => FactValue::Bool(ctx.policy.as_ref()?.authority_frozen),
+        };
+        Some(value)
+    }
+}
+
+impl<'a> FactValue<'a> {
+    fn from_option(value: &'a Option<String>) -> Self {
+        match value {
+            Some(value) => FactValue::Str(value),
+            None => FactValue::Null,
+        }
+    }
+
+    fn from_u64(value: u64) -> Self {
+        match i64::try_from(value) {
+            Ok(value) => FactValue::Int(value),
+            Err(_) => FactValue::Null,
+        }
+    }
+
+    fn as_str(&self) -> Option<&'a str> {
+        match self {
+            FactValue::Str(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn as_i64(&self) -> Option<i64> {
+        match self {
+            FactValue::Int(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    fn as_bool(&self) -> Option<bool> {
+        match self {
+            FactValue::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    fn as_str_array(&self) -> Option<&'a [String]> {
+        match self {
+            FactValue::StrArray(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    // json_rules_engine_fork filters out the non-i64 items, so an array of strings is an empty array of i64
+    fn as_i64_array(&self) -> Option<Vec<i64>> {
+        match self {
+            FactValue::StrArray(_) => Some(vec![]),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for FactValue<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FactValue::Str(value) => write!(f, "{}", value),
+            FactValue::StrArray(value) => write!(f, "[{}]", value.join(",")),
+            FactValue::Int(value) => write!(f, "{}", value),
+            FactValue::Bool(value) => write!(f, "{}", value),
+            FactValue::Null => write!(f, "null"),
+        }
+    }
+}
+
+pub fn to_trace_status(status: Status) -> u8 {
+    match status {
+        Status::Met => TRACE_STATUS_MET,
+        Status::NotMet => TRACE_STATUS_NOT_MET,
+        Status::Unknown => TRACE_STATUS_UNKNOWN,
+    }
+}
+
+fn truncate(s: &str) -> String {
+    s.chars().take(TRACE_STR_MAX_LEN).collect()
+}
+
+fn to_status(met: bool) -> Status {
+    match met {
+        true => Status::Met,
+        false => Status::NotMet,
+    }
+}
+
+impl CompiledConstraint {
+    pub fn compile(constraint: &Constraint) -> Option<Self> {
+        let compiled = match constraint {
+            Constraint::StringEquals(s) => Self::StringEquals(s.clone()),
+            Constraint::StringNotEquals(s) => Self::StringNotEquals(s.clone()),
+            Constraint::StringContains(s) => Self::StringContains(s.clone()),
+            Constraint::StringContainsAll(s) => Self::StringContainsAll(s.clone()),
+            Constraint::StringContainsAny(s) => Self::StringContainsAny(s.clone()),
+            Constraint::StringDoesNotContain(s) => Self::StringDoesNotContain(s.clone()),
+            Constraint::StringDoesNotContainAny(s) => Self::StringDoesNotContainAny(s.clone()),
+            Constraint::StringIn(s) => Self::StringIn(s.clone()),
+            Constraint::StringNotIn(s) => Self::StringNotIn(s.clone()),
+            Constraint::StringIsSubset(s) => Self::StringIsSubset(s.clone()),
+            Constraint::StringIsSubstring(s) => Self::StringIsSubstring(s.clone()),
+            Constraint::StringHasSubstring(s) => Self::StringHasSubstring(s.clone()),
+            Constraint::IntEquals(num) => Self::IntEquals(*num),
+            Constraint::IntNotEquals(num) => Self::IntNotEquals(*num),
+            Constraint::IntContains(num) => Self::IntContains(*num),
+            Constraint::IntContainsAll(nums) => Self::IntContainsAll(nums.clone()),
+            Constraint::IntContainsAny(nums) => Self::IntContainsAny(nums.clone()),
+            Constraint::IntDoesNotContain(num) => Self::IntDoesNotContain(*num),
+            Constraint::IntDoesNotContainAny(nums) => Self::IntDoesNotContainAny(nums.clone()),
+            Constraint::IntIn(nums) => Self::IntIn(nums.clone()),
+            Constraint::IntNotIn(nums) => Self::IntNotIn(nums.clone()),
+            Constraint::IntInRange(start, end) => Self::IntInRange(*start, *end),
+            Constraint::IntNotInRange(start, end) => Self::IntNotInRange(*start, *end),
+            Constraint::IntLessThan(num) => Self::IntLessThan(*num),
+            Constraint::IntLessThanInclusive(num) => Self::IntLessThanInclusive(*num),
+            Constraint::IntGreaterThan(num) => Self::IntGreaterThan(*num),
+            Constraint::IntGreaterThanInclusive(num) => Self::IntGreaterThanInclusive(*num),
+            Constraint::BoolEquals(b) => Self::BoolEquals(*b),
+            _ => return None, // float operators
+        };
+        Some(compiled)
+    }
+
+    pub fn compile_extended(constraint: &ExtendedConstraint) -> Option<Self> {
+        let compiled = match constraint {
+            ExtendedConstraint::InList(list) => Self::InList(Pubkey::from_str(list).ok()?),
+            ExtendedConstraint::NotInList(list) => Self::NotInList(Pubkey::from_str(list).ok()?),
+            ExtendedConstraint::AllInList(list) => Self::AllInList(Pubkey::from_str(list).ok()?),
+            ExtendedConstraint::NoneInList(list) => Self::NoneInList(Pubkey::from_str(list).ok()?),
+            ExtendedConstraint::InMerkleTree(root) => Self::InMerkleTree(Pubkey::from_str(root).ok()?.to_bytes()),
+            ExtendedConstraint::AllInMerkleTree(root) => Self::AllInMerkleTree(Pubkey::from_str(root).ok()?.to_bytes()),
+        };
+        Some(compiled)
+    }
+
+    // the same status as json_rules_engine_fork::Constraint::check_value, a value of the wrong type is NotMet.
+    // The list operators are Unknown when the ListAccount is not found in the accounts (or not frozen for a frozen policy), the merkle operators are
+    // NotMet without a valid proof in the ctx
+    pub fn check_value(&self, v: &FactValue, ctx: &ActionCtx, accounts: &[AccountInfo]) -> Status {
+        let in_merkle_tree = |root: &MerkleNode, key: &str| match Pubkey::from_str(key) {
+            Ok(key) => verify_merkle_proofs(root, &key, &ctx.merkle_proofs),
+            Err(_) => false,
+        };
+        // a frozen policy only trusts the frozen lists, see freeze_list
+        let frozen_only = ctx.policy.as_ref().is_some_and(|x| x.frozen);
+        let in_list = |list: &Pubkey, key: &str| -> Option<bool> {
+            match Pubkey::from_str(key) {
+                Ok(key) => ListAccount::contains(list, &key, accounts, frozen_only),
+                Err(_) => Some(false), // not a pubkey, so it can't be in the list
+            }
+        };
+        let list_status = |met: Option<Option<bool>>| match met {
+            Some(Some(met)) => to_status(met),
+            Some(None) => Status::Unknown,
+            None => Status::NotMet,
+        };
+        let met = match self {
+            Self::InList(list) => return list_status(v.as_str().map(|v| in_list(list, v))),
+            Self::NotInList(list) => return list_status(v.as_str().map(|v| in_list(list, v).map(|x| !x))),
+            Self::AllInList(list) => {
+                return list_status(v.as_str_array().map(|v| {
+                    v.iter()
+                        .map(|y| in_list(list, y))
+                        .collect::<Option<Vec<bool>>>()
+                        .map(|x| x.into_iter().all(|y| y))
+                }))
+            }
+            Self::NoneInList(list) => {
+                return list_status(v.as_str_array().map(|v| {
+                    v.iter()
+                        .map(|y| in_list(list, y))
+                        .collect::<Option<Vec<bool>>>()
+                        .map(|x| x.into_iter().all(|y| !y))
+                }))
+            }
+            Self::InMerkleTree(root) => v.as_str().map(|v| in_merkle_tree(root, v)),
+            Self::AllInMerkleTree(root) => v.as_str_array().map(|v| v.iter().all(|y| in_merkle_tree(root, y))),
+            Self::StringEquals(s) => v.as_str().map(|v| v == s),
+            Self::StringNotEquals(s) => v.as_str().map(|v| v != s),
+            Self::StringContains(s) => v.as_str_array().map(|v| v.contains(s)),
+            Self::StringContainsAll(s) => v.as_str_array().map(|v| s.iter().all(|y| v.contains(y))),
+            Self::StringContainsAny(s) => v.as_str_array().map(|v| s.iter().any(|y| v.contains(y))),
+            Self::StringDoesNotContain(s) => v.as_str_array().map(|v| !v.contains(s)),
+            Self::StringDoesNotContainAny(s) => v.as_str_array().map(|v| s.iter().all(|y| !v.contains(y))),
+            Self::StringIn(ss) => v.as_str().map(|v| ss.iter().any(|s| s == v)),
+            Self::StringNotIn(ss) => v.as_str().map(|v| ss.iter().all(|s| s != v)),
+            Self::StringIsSubset(s) => v.as_str_array().map(|v| v.iter().all(|y| s.contains(y))),
+            Self::StringIsSubstring(s) => v.as_str().map(|v| s.contains(v)),
+            Self::StringHasSubstring(s) => v.as_str().map(|v| v.contains(s.as_str())),
+            Self::IntEquals(num) => v.as_i64().map(|v| v == *num),
+            Self::IntNotEquals(num) => v.as_i64().map(|v| v != *num),
+            Self::IntContains(num) => v.as_i64_array().map(|v| v.contains(num)),
+            Self::IntContainsAll(nums) => v.as_i64_array().map(|v| nums.iter().all(|num| v.contains(num))),
+            Self::IntContainsAny(nums) => v.as_i64_array().map(|v| nums.iter().any(|num| v.contains(num))),
+            Self::IntDoesNotContain(num) => v.as_i64_array().map(|v| !v.contains(num)),
+            Self::IntDoesNotContainAny(nums) => v.as_i64_array().map(|v| nums.iter().all(|num| !v.contains(num))),
+            Self::IntIn(nums) => v.as_i64().map(|v| nums.contains(&v)),
+            Self::IntNotIn(nums) => v.as_i64().map(|v| !nums.contains(&v)),
+            Self::IntInRange(start, end) => v.as_i64().map(|v| *start <= v && v <= *end),
+            Self::IntNotInRange(start, end) => v.as_i64().map(|v| !(*start <= v && v <= *end)),
+            Self::IntLessThan(num) => v.as_i64().map(|v| v < *num),
+            Self::IntLessThanInclusive(num) => v.as_i64().map(|v| v <= *num),
+            Self::IntGreaterThan(num) => v.as_i64().map(|v| v > *num),
+            Self::IntGreaterThanInclusive(num) => v.as_i64().map(|v| v >= *num),
+            Self::BoolEquals(b) => v.as_bool().map(|v| v == *b),
+        };
+        to_status(met.unwrap_or(false))
+    }
+}
+
+// same as evaluate_json_rule, the json rules engine only tells the names and the statuses of the conditions
+pub fn trace_json_rule(json_rule: &[u8], ctx: &ActionCtx, accounts: &[AccountInfo]) -> Option<(Status, Vec<ConditionTrace>)> {
+    let rule = serde_json::from_slice::<JsonRule>(json_rule).ok()?;
+    match CompiledRule::compile(&rule) {
+        Some(compiled_rule) => Some(compiled_rule.trace(ctx, accounts)),
+        None => {
+            let rule = serde_json::from_slice::<json_rules_engine_fork::Rule>(json_rule).ok()?;
+            let fact = serde_json::to_value::<&ActionCtx>(ctx).ok()?;
+            let result = rule.check_value(&fact).condition_result;
+            let mut trace = vec![];
+            trace_condition_result(&result, 0, &mut trace);
+            Some((result.status, trace))
+        }
+    }
+}
+
+fn trace_condition_result(result: &ConditionResult, depth: u8, trace: &mut Vec<ConditionTrace>) {
+    trace.push(ConditionTrace {
+        depth,
+        name: truncate(&result.name),
+        fact: None,
+        status: to_trace_status(result.status),
+    });
+    for child in &result.children {
+        trace_condition_result(child, depth.saturating_add(1), trace);
+    }
+}
+
+// evaluates a json_rule that isn't compiled ahead of time, e.g. in a rule chunk
+pub fn evaluate_json_rule(json_rule: &[u8], ctx: &ActionCtx, accounts: &[AccountInfo]) -> Option<(Status, Vec<RuleEvent>, Option<ConditionError>)> {
+    let rule = serde_json::from_slice::<JsonRule>(json_rule).ok()?;
+    match CompiledRule::compile(&rule) {
+        Some(compiled_rule) => {
+            let status = compiled_rule.evaluate(ctx, accounts);
+            let error = match status {
+                Status::Met => None,
+                _ => compiled_rule.conditions.find_error(ctx, accounts).cloned(),
+            };
+            Some((status, compiled_rule.events, error))
+        }
+        None => {
+            let events = rule.compile_events()?;
+            let rule = serde_json::from_slice::<json_rules_engine_fork::Rule>(json_rule).ok()?;
+            let fact = serde_json::to_value::<&ActionCtx>(ctx).ok()?;
+            Some((rule.check_value(&fact).condition_result.status, events, None))
+        }
+    }
+}
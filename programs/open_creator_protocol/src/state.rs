@@ -1,9 +1,23 @@
-use crate::{action::ActionCtx, errors::OCPErrorCode, royalty::DynamicRoyalty};
+use crate::{
+    action::ActionCtx,
+    errors::OCPErrorCode,
+    royalty::{
+        get_royalty_amount, DynamicRoyalty, RoyaltyRecipient, ROYALTY_ENFORCEMENT_MODE_INSTRUCTION_INTROSPECTION, ROYALTY_ENFORCEMENT_MODE_NONE,
+    },
+    rule::{evaluate_json_rule, get_matched_events, CompiledRule, JsonRule, RuleEvent},
+};
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke;
 use anchor_lang::solana_program::pubkey::Pubkey;
+use anchor_lang::solana_program::system_instruction;
+use anchor_lang::system_program;
+use anchor_lang::Discriminator;
+use anchor_spl::metadata::MetadataAccount;
 use json_rules_engine_fork::{Rule, Status};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::cmp::max;
+use std::str::FromStr;
 
 #[account]
 #[derive(Default, Serialize, Deserialize)]
@@ -16,6 +30,7 @@ pub struct MintState {
     pub last_approved_at: i64,
     pub last_transferred_at: i64,
     pub transferred_count: u32,
+    pub sale_count: u64, // the royalty receipts of the mint, the seed of the next one. Unlike transferred_count, it doesn't saturate
 }
 
 impl MintState {
@@ -29,6 +44,12 @@ impl MintState {
     pub fn record_approve(&mut self) {
         self.last_approved_at = Clock::get().unwrap().unix_timestamp;
     }
+    pub fn get_holding_duration(&self) -> i64 {
+        self.get_holding_duration_at(Clock::get().unwrap().unix_timestamp)
+    }
+    pub fn get_holding_duration_at(&self, now: i64) -> i64 {
+        max(0, now - self.last_transferred_at)
+    }
 }
 
 #[account]
@@ -40,23 +61,131 @@ pub struct Policy {
     pub authority: Pubkey,
     pub dynamic_royalty: Option<DynamicRoyalty>,
     pub json_rule: Option<String>,
+    pub royalty_split: Option<Vec<RoyaltyRecipient>>, // if not set, the royalty goes to the metadata creators
+    pub royalty_enforcement_mode: u8,
+    pub compiled_rule: Option<CompiledRule>, // compiled from the json_rule, None if it can't be compiled
+    pub rule_chunk_count: u8,                // the rule chunks [0, rule_chunk_count) must match as well
+    pub action_rules: Vec<ActionRule>,       // overrides the json_rule for the listed actions
+    pub update_delay: i64,                   // seconds between propose_policy_update and apply_policy_update, 0 for update_policy
+    pub pending_authority: Option<Pubkey>,   // nominated by the authority, becomes the authority once it accepts
+    pub rule_admin: Option<Pubkey>,          // the rules and the timelocked updates, None for the authority
+    pub royalty_admin: Option<Pubkey>,       // the royalties and the royalty exemption, None for the authority
+    pub pause_guardian: Option<Pubkey>,      // the emergency actions, None for the authority
+    pub unmanaged: bool,                     // opts out of the managed authorities of the ProgramConfig
+    pub frozen: bool,                        // set by freeze_policy, the rules and the royalties can't be updated anymore
+    pub authority_frozen: bool,              // set by freeze_policy, the authority can't be transferred anymore
+    pub account_count: u32,                  // the rule chunks, rule counters, royalty exemption, pending update and mint counter
+    pub paused: bool,                        // set by the pause guardian with set_policy_paused, every action fails while set
+}
+
+// the rule of one action. The policy's json_rule is the default rule of the actions without an ActionRule,
+// and an empty json_rule lets the action pass
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, AnchorSerialize, AnchorDeserialize)]
+pub struct ActionRule {
+    pub action: String,
+    pub json_rule: String,
+    pub compiled_rule: Option<CompiledRule>,
 }
 
 impl Policy {
-    pub const LEN: usize = Policy::JSON_RULE_MAX_LEN + 400 /* with padding */;
+    pub const LEN: usize = Policy::JSON_RULE_MAX_LEN + Policy::COMPILED_RULE_MAX_LEN + 1000 /* with padding */;
     pub const SEED: &'static str = "policy";
-    pub const MANAGED_AUTHORITY: &'static str = "RULERZZDGsXqd9TeJu5ikLfbXzBFpoDPT8N3FHRhq1T";
+    pub const VERSION: u8 = 1; // the policies of version 0 were created before the account_count and the mint counter, and can never be closed
     pub const JSON_RULE_MAX_LEN: usize = 1000;
+    pub const COMPILED_RULE_MAX_LEN: usize = 1000;
+    pub const ROYALTY_SPLIT_MAX_RECIPIENTS: usize = 5;
+    pub const RULE_CHUNKS_MAX: u8 = 8;
+    pub const UPDATE_DELAY_MAX: i64 = 30 * 24 * 60 * 60;
+    pub const ACTIONS: [&'static str; 11] = [
+        "approve",
+        "burn",
+        "close",
+        "init_account",
+        "lock",
+        "migrate_to_mpl",
+        "mint_to",
+        "revoke",
+        "transfer",
+        "unlock",
+        "wrap",
+    ];
+
+    // whether the update changes the royalty fields, which is for the royalty admin
+    pub fn is_royalty_update(&self, update: &PolicyUpdate) -> Result<bool> {
+        Ok(self.dynamic_royalty.try_to_vec()? != update.dynamic_royalty.try_to_vec()?
+            || self.royalty_split.try_to_vec()? != update.royalty_split.try_to_vec()?
+            || self.royalty_enforcement_mode != update.royalty_enforcement_mode)
+    }
+
+    pub fn is_rule_update(&self, update: &PolicyUpdate) -> bool {
+        self.json_rule != update.json_rule
+            || self.rule_chunk_count != update.rule_chunk_count
+            || self.update_delay != update.update_delay
+            || self.action_rules.len() != update.action_rules.len()
+            || self
+                .action_rules
+                .iter()
+                .zip(update.action_rules.iter())
+                .any(|(x, y)| x.action != y.action || x.json_rule != y.json_rule)
+    }
+
+    // the royalty fields need the royalty admin, and the other fields need the rule admin
+    pub fn check_update_authority(&self, key: &Pubkey, update: &PolicyUpdate, program_config: Option<&ProgramConfig>) -> Result<()> {
+        if self.is_royalty_update(update)? && !self.is_royalty_admin(key, program_config) {
+            msg!("only the royalty admin can update the royalty fields");
+            return Err(OCPErrorCode::InvalidAuthority.into());
+        }
+        if self.is_rule_update(update) && !self.is_rule_admin(key, program_config) {
+            msg!("only the rule admin can update the rule fields");
+            return Err(OCPErrorCode::InvalidAuthority.into());
+        }
+        Ok(())
+    }
+
+    // overwrites the updatable fields, same as update_policy
+    pub fn update(&mut self, update: PolicyUpdate) -> Result<()> {
+        if self.frozen {
+            return Err(OCPErrorCode::PolicyFrozen.into());
+        }
+        self.json_rule = update.json_rule;
+        self.dynamic_royalty = update.dynamic_royalty;
+        self.royalty_split = update.royalty_split;
+        self.royalty_enforcement_mode = update.royalty_enforcement_mode;
+        self.rule_chunk_count = update.rule_chunk_count;
+        self.action_rules = update.action_rules;
+        self.update_delay = update.update_delay;
+        self.compile_rule();
+        self.valid()
+    }
 
     pub fn valid(&self) -> Result<()> {
-        match &self.json_rule {
-            Some(json_rule) => {
-                if json_rule.len() > Policy::JSON_RULE_MAX_LEN {
-                    return Err(OCPErrorCode::InvalidPolicyCreation.into());
-                }
-                serde_json::from_str::<Rule>(json_rule).expect("json_rule should be valid");
+        if self
+            .action_rules
+            .iter()
+            .enumerate()
+            .any(|(i, x)| !Policy::ACTIONS.contains(&x.action.as_str()) || self.action_rules[..i].iter().any(|y| y.action == x.action))
+        {
+            msg!("action_rules must be of distinct actions in {:?}", Policy::ACTIONS);
+            return Err(OCPErrorCode::InvalidPolicyCreation.into());
+        }
+        // the json_rule and the action_rules share the same json and compiled size budgets
+        if self.json_rules().map(|x| x.len()).sum::<usize>() > Policy::JSON_RULE_MAX_LEN {
+            return Err(OCPErrorCode::InvalidPolicyCreation.into());
+        }
+        let mut compiled_budget = Policy::COMPILED_RULE_MAX_LEN;
+        for json_rule in self.json_rules() {
+            let rule = serde_json::from_str::<JsonRule>(json_rule).expect("json_rule should be valid");
+            if rule.compile_events().is_none() {
+                msg!("json_rule has invalid events of the types {:?}", JsonRule::EVENT_TYPES);
+                return Err(OCPErrorCode::InvalidPolicyCreation.into());
+            }
+            if Policy::compile_json_rule(&rule, &mut compiled_budget).is_none() && rule.has_extended_operators() {
+                msg!(
+                    "json_rule with the extended operators must compile within {} bytes",
+                    Policy::COMPILED_RULE_MAX_LEN
+                );
+                return Err(OCPErrorCode::InvalidPolicyCreation.into());
             }
-            None => {}
         }
         match &self.dynamic_royalty {
             Some(dynamic_royalty) => {
@@ -64,40 +193,620 @@ impl Policy {
             }
             None => {}
         }
+        if self.update_delay < 0 || self.update_delay > Policy::UPDATE_DELAY_MAX {
+            msg!("update_delay must be between 0 and {} seconds", Policy::UPDATE_DELAY_MAX);
+            return Err(OCPErrorCode::InvalidPolicyCreation.into());
+        }
+        if self.rule_chunk_count > Policy::RULE_CHUNKS_MAX {
+            msg!("rule_chunk_count must be less than or equal to {}", Policy::RULE_CHUNKS_MAX);
+            return Err(OCPErrorCode::InvalidPolicyCreation.into());
+        }
+        match self.royalty_enforcement_mode {
+            ROYALTY_ENFORCEMENT_MODE_NONE => {}
+            ROYALTY_ENFORCEMENT_MODE_INSTRUCTION_INTROSPECTION => {
+                if self.get_price_mint().is_some() {
+                    msg!("instruction introspection only supports royalties paid in SOL");
+                    return Err(OCPErrorCode::InvalidPriceMint.into());
+                }
+            }
+            _ => {
+                msg!("Invalid royalty_enforcement_mode");
+                return Err(OCPErrorCode::InvalidPolicyCreation.into());
+            }
+        }
+        if let Some(royalty_split) = &self.royalty_split {
+            if royalty_split.is_empty() || royalty_split.len() > Policy::ROYALTY_SPLIT_MAX_RECIPIENTS {
+                msg!("royalty_split must have 1 to {} recipients", Policy::ROYALTY_SPLIT_MAX_RECIPIENTS);
+                return Err(OCPErrorCode::InvalidRoyaltySplit.into());
+            }
+            if royalty_split.iter().map(|x| x.share as u32).sum::<u32>() != 100 {
+                msg!("royalty_split shares must sum to 100");
+                return Err(OCPErrorCode::InvalidRoyaltySplit.into());
+            }
+            if royalty_split
+                .iter()
+                .enumerate()
+                .any(|(i, x)| royalty_split[..i].iter().any(|y| y.address == x.address))
+            {
+                msg!("royalty_split recipients must be unique");
+                return Err(OCPErrorCode::InvalidRoyaltySplit.into());
+            }
+        }
         Ok(())
     }
 
-    pub fn is_managed(&self) -> bool {
-        self.authority.to_string() == Policy::MANAGED_AUTHORITY
+    // called whenever the json_rule changes. A rule that can't be compiled or is too large once compiled
+    // is left to the json rules engine
+    pub fn compile_rule(&mut self) {
+        let mut compiled_budget = Policy::COMPILED_RULE_MAX_LEN;
+        let compile = |json_rule: &str, compiled_budget: &mut usize| match json_rule.is_empty() {
+            true => None,
+            false => serde_json::from_str::<JsonRule>(json_rule)
+                .ok()
+                .and_then(|rule| Policy::compile_json_rule(&rule, compiled_budget)),
+        };
+        self.compiled_rule = compile(self.json_rule.as_deref().unwrap_or_default(), &mut compiled_budget);
+        for action_rule in self.action_rules.iter_mut() {
+            action_rule.compiled_rule = compile(&action_rule.json_rule, &mut compiled_budget);
+        }
+    }
+
+    // compiles the rule if it fits in what's left of the compiled size budget
+    fn compile_json_rule(rule: &JsonRule, compiled_budget: &mut usize) -> Option<CompiledRule> {
+        let compiled_rule = CompiledRule::compile(rule)?;
+        let len = compiled_rule.try_to_vec().ok()?.len();
+        *compiled_budget = compiled_budget.checked_sub(len)?;
+        Some(compiled_rule)
+    }
+
+    // the non-empty json_rule and action_rules, in the order they are compiled
+    fn json_rules(&self) -> impl Iterator<Item = &str> {
+        self.json_rule
+            .iter()
+            .map(|x| x.as_str())
+            .chain(self.action_rules.iter().map(|x| x.json_rule.as_str()))
+            .filter(|x| !x.is_empty())
+    }
+
+    // the version 0 policies count neither their mints nor their accounts
+    pub fn is_counted(&self) -> bool {
+        self.version >= Policy::VERSION
+    }
+
+    // a PDA of the policy is created, see close_policy
+    pub fn add_account(&mut self) -> Result<()> {
+        self.account_count = self.account_count.checked_add(1).ok_or(OCPErrorCode::NumericalOverflow)?;
+        Ok(())
+    }
+
+    // the accounts created before the account_count are not counted, so the count of a version 0 policy can be too low
+    pub fn remove_account(&mut self) {
+        self.account_count = self.account_count.saturating_sub(1);
+    }
+
+    // no PDA of the policy is left, including the mint counter that has to be 0 to be closed, see close_policy
+    pub fn is_closable(&self) -> bool {
+        self.is_counted() && self.account_count == 0
+    }
+
+    pub fn is_managed(&self, program_config: Option<&ProgramConfig>) -> bool {
+        self.is_managed_authority(&self.authority, program_config)
+    }
+
+    // a managed authority of the ProgramConfig, unless the policy opted out
+    pub fn is_managed_authority(&self, key: &Pubkey, program_config: Option<&ProgramConfig>) -> bool {
+        !self.unmanaged && program_config.is_some_and(|x| x.managed_authorities.contains(key))
+    }
+
+    // the managed authorities can act as the authority and as every role
+    pub fn is_authority(&self, key: &Pubkey, program_config: Option<&ProgramConfig>) -> bool {
+        *key == self.authority || self.is_managed_authority(key, program_config)
+    }
+
+    pub fn is_rule_admin(&self, key: &Pubkey, program_config: Option<&ProgramConfig>) -> bool {
+        *key == self.rule_admin.unwrap_or(self.authority) || self.is_managed_authority(key, program_config)
+    }
+
+    pub fn is_royalty_admin(&self, key: &Pubkey, program_config: Option<&ProgramConfig>) -> bool {
+        *key == self.royalty_admin.unwrap_or(self.authority) || self.is_managed_authority(key, program_config)
+    }
+
+    pub fn is_pause_guardian(&self, key: &Pubkey, program_config: Option<&ProgramConfig>) -> bool {
+        *key == self.pause_guardian.unwrap_or(self.authority) || self.is_managed_authority(key, program_config)
+    }
+
+    // either admin can propose an update, see check_update_authority for the fields each can change
+    pub fn is_update_admin(&self, key: &Pubkey, program_config: Option<&ProgramConfig>) -> bool {
+        self.is_rule_admin(key, program_config) || self.is_royalty_admin(key, program_config)
     }
 
-    pub fn matches(&self, ctx: &ActionCtx) -> Result<()> {
-        match &self.json_rule {
+    // the rule chunks and the accounts of the rule events are looked up in the remaining_accounts
+    pub fn matches(&self, ctx: &ActionCtx, remaining_accounts: &[AccountInfo]) -> Result<()> {
+        if self.paused {
+            msg!("Policy is paused by the pause guardian");
+            return Err(OCPErrorCode::PolicyPaused.into());
+        }
+        let events = self.evaluate(ctx, remaining_accounts)?;
+        self.apply_events(&events, ctx, remaining_accounts)
+    }
+
+    // evaluates the policy without side effects, and returns the events of the matched rules
+    pub fn evaluate(&self, ctx: &ActionCtx, remaining_accounts: &[AccountInfo]) -> Result<Vec<RuleEvent>> {
+        let mut events = self.evaluate_rule(ctx, remaining_accounts)?;
+        if self.rule_chunk_count > 0 {
+            let policy = self.get_address()?;
+            for index in 0..self.rule_chunk_count {
+                events.extend(RuleChunk::load(&policy, index, remaining_accounts)?.evaluate(ctx, remaining_accounts)?);
+            }
+        }
+        Ok(events)
+    }
+
+    // the chunks that an update starts to reference are parsed on every action from then on, so they must be complete.
+    // The referenced chunks can't be written anymore, see write_rule_chunk
+    pub fn check_new_rule_chunks(&self, previous_rule_chunk_count: u8, remaining_accounts: &[AccountInfo]) -> Result<()> {
+        if self.rule_chunk_count <= previous_rule_chunk_count {
+            return Ok(());
+        }
+        let policy = self.get_address()?;
+        for index in previous_rule_chunk_count..self.rule_chunk_count {
+            RuleChunk::load(&policy, index, remaining_accounts)?.valid()?;
+        }
+        Ok(())
+    }
+
+    // a frozen policy only trusts the frozen lists, see ListAccount::contains. Every list that the rules reference
+    // must be frozen, and passed in the remaining accounts with the rule chunks, before the policy is frozen
+    pub fn check_frozen_lists(&self, remaining_accounts: &[AccountInfo]) -> Result<()> {
+        let mut lists = vec![];
+        let compiled_rules = self
+            .compiled_rule
+            .iter()
+            .chain(self.action_rules.iter().filter_map(|x| x.compiled_rule.as_ref()));
+        compiled_rules.for_each(|x| x.conditions.lists(&mut lists));
+        if self.rule_chunk_count > 0 {
+            let policy = self.get_address()?;
+            for index in 0..self.rule_chunk_count {
+                let rule_chunk = RuleChunk::load(&policy, index, remaining_accounts)?;
+                // the list operators only evaluate in the compiled rules
+                if let Some(rule) = serde_json::from_slice::<JsonRule>(&rule_chunk.json_rule)
+                    .ok()
+                    .and_then(|x| CompiledRule::compile(&x))
+                {
+                    rule.conditions.lists(&mut lists);
+                }
+            }
+        }
+        for list in lists {
+            if !ListAccount::is_frozen(&list, remaining_accounts) {
+                msg!("list {} must be frozen and passed in the remaining accounts", list);
+                return Err(OCPErrorCode::InvalidListAccount.into());
+            }
+        }
+        Ok(())
+    }
+
+    pub fn get_action_rule(&self, action: &str) -> Option<&ActionRule> {
+        self.action_rules.iter().find(|x| x.action == action)
+    }
+
+    // the ActionRule of the action if any, otherwise the json_rule
+    fn evaluate_rule(&self, ctx: &ActionCtx, remaining_accounts: &[AccountInfo]) -> Result<Vec<RuleEvent>> {
+        let (json_rule, compiled_rule) = match self.get_action_rule(&ctx.action) {
+            Some(action_rule) => (Some(&action_rule.json_rule), &action_rule.compiled_rule),
+            None => (self.json_rule.as_ref(), &self.compiled_rule),
+        };
+        if let Some(compiled_rule) = compiled_rule {
+            let status = compiled_rule.evaluate(ctx, remaining_accounts);
+            let mut error = None;
+            if status != Status::Met {
+                msg!("Policy does not match: {}", compiled_rule.conditions.name());
+                error = compiled_rule.conditions.find_error(ctx, remaining_accounts);
+            }
+            return get_matched_events(status, &compiled_rule.events, error, ctx);
+        }
+
+        match json_rule {
             Some(json_rule) => {
                 if json_rule.is_empty() {
-                    return Ok(());
+                    return Ok(vec![]);
                 }
+                let events = serde_json::from_str::<JsonRule>(json_rule)
+                    .ok()
+                    .and_then(|x| x.compile_events())
+                    .ok_or(OCPErrorCode::InvalidRuleEvent)?;
                 let rule: Rule = serde_json::from_str::<Rule>(json_rule).expect("json_rule should be valid");
                 let fact: &Value = &serde_json::to_value::<&ActionCtx>(ctx).expect("action_ctx should be serializable");
                 let result = rule.check_value(fact);
                 if result.condition_result.status != Status::Met {
                     msg!("Policy does not match: {}", result.condition_result.name);
                     msg!("fact: {}", fact);
-                    return Err(OCPErrorCode::InvalidPolicyEvaluation.into());
                 }
+                get_matched_events(result.condition_result.status, &events, None, ctx)
             }
-            None => {}
+            None => Ok(vec![]),
         }
+    }
 
+    fn apply_events(&self, events: &[RuleEvent], ctx: &ActionCtx, remaining_accounts: &[AccountInfo]) -> Result<()> {
+        for event in events {
+            match event {
+                RuleEvent::EmitError(_) | RuleEvent::RequireMemo => {} // checked in get_matched_events
+                RuleEvent::ChargeFee(lamports, recipient) => {
+                    let payer = match ctx.payer.as_ref().or(ctx.from.as_ref()).map(|x| Pubkey::from_str(x)) {
+                        Some(Ok(payer)) => payer,
+                        _ => return Err(OCPErrorCode::InvalidRuleEvent.into()),
+                    };
+                    let payer = remaining_accounts
+                        .iter()
+                        .find(|x| x.key == &payer && x.is_signer && x.is_writable)
+                        .ok_or(OCPErrorCode::AccountNotFound)?;
+                    let recipient = remaining_accounts
+                        .iter()
+                        .find(|x| x.key == recipient && x.is_writable)
+                        .ok_or(OCPErrorCode::AccountNotFound)?;
+                    let mut account_infos = vec![payer.clone(), recipient.clone()];
+                    account_infos.extend(remaining_accounts.iter().find(|x| x.key == &system_program::ID).cloned());
+                    invoke(&system_instruction::transfer(payer.key, recipient.key, *lamports), &account_infos)?;
+                }
+                RuleEvent::IncrementCounter(index) => {
+                    let (account, mut rule_counter) = RuleCounter::load(&self.get_address()?, *index, remaining_accounts)?;
+                    rule_counter.count = rule_counter.count.saturating_add(1);
+                    rule_counter.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+                }
+            }
+        }
         Ok(())
     }
 
+    pub fn get_royalty_bp(&self, price: u64, holding_duration: i64, is_pass_holder: bool, metadata_royalty_bp: u16) -> u16 {
+        match &self.dynamic_royalty {
+            Some(dynamic_royalty) => dynamic_royalty.get_royalty_bp(price, holding_duration, is_pass_holder, metadata_royalty_bp),
+            None => metadata_royalty_bp,
+        }
+    }
+
+    pub fn get_royalty_amount(&self, price: u64, royalty_bp: u16) -> Result<u64> {
+        match &self.dynamic_royalty {
+            Some(dynamic_royalty) => dynamic_royalty.get_royalty_amount(price, royalty_bp),
+            None => get_royalty_amount(price, royalty_bp),
+        }
+    }
+
+    pub fn get_royalty_recipients(&self, metadata: &MetadataAccount) -> Vec<RoyaltyRecipient> {
+        match &self.royalty_split {
+            Some(royalty_split) => royalty_split.clone(),
+            None => metadata
+                .creators
+                .as_ref()
+                .map(|creators| {
+                    creators
+                        .iter()
+                        .map(|creator| RoyaltyRecipient {
+                            address: creator.address,
+                            share: creator.share,
+                        })
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }
+    }
+
+    pub fn is_pass_holder(&self, holder: &Pubkey, pass_accounts: &[AccountInfo]) -> Result<bool> {
+        match &self.dynamic_royalty {
+            Some(dynamic_royalty) => dynamic_royalty.is_pass_holder(holder, pass_accounts),
+            None => Ok(false),
+        }
+    }
+
+    // the currency that the sale price and royalty are denominated in, None for SOL
+    pub fn get_price_mint(&self) -> Option<Pubkey> {
+        self.dynamic_royalty.as_ref().and_then(|x| x.get_price_mint())
+    }
+
     pub fn signer_seeds(&self) -> [&[u8]; 3] {
         [Policy::SEED.as_bytes(), self.uuid.as_ref(), &self.bump]
     }
 
+    pub fn get_address(&self) -> Result<Pubkey> {
+        Pubkey::create_program_address(&self.signer_seeds(), &crate::id()).map_err(|_| OCPErrorCode::InvalidPolicyMintAssociation.into())
+    }
+
     pub fn get_freeze_authority(&self, upstream_authority: Pubkey) -> Pubkey {
         let (freeze_authority, _) = Pubkey::find_program_address(&[upstream_authority.as_ref()], &community_managed_token::id());
         freeze_authority
     }
 }
+
+// the program-level config, set by the upgrade authority of the program
+#[account]
+#[derive(Default, Serialize, Deserialize)]
+pub struct ProgramConfig {
+    pub version: u8,
+    pub bump: [u8; 1],
+    pub managed_authorities: Vec<Pubkey>, // can act on the policies that didn't opt out, see Policy::is_managed_authority
+}
+
+impl ProgramConfig {
+    pub const MANAGED_AUTHORITIES_MAX: usize = 8;
+    pub const LEN: usize = 8 + 1 + 1 + 4 + 32 * ProgramConfig::MANAGED_AUTHORITIES_MAX;
+    pub const SEED: &'static str = "program_config";
+}
+
+// the updatable fields of a policy
+#[derive(Default, Clone, Serialize, Deserialize, AnchorSerialize, AnchorDeserialize)]
+pub struct PolicyUpdate {
+    pub json_rule: Option<String>,
+    pub dynamic_royalty: Option<DynamicRoyalty>,
+    pub royalty_split: Option<Vec<RoyaltyRecipient>>,
+    pub royalty_enforcement_mode: u8,
+    pub rule_chunk_count: u8,
+    pub action_rules: Vec<ActionRule>, // not compiled yet
+    pub update_delay: i64,
+}
+
+// the update of a timelocked policy, applied by apply_policy_update once effective_at has passed
+#[account]
+#[derive(Default, Serialize, Deserialize)]
+pub struct PendingPolicyUpdate {
+    pub version: u8,
+    pub bump: [u8; 1],
+    pub policy: Pubkey,
+    pub proposed_at: i64,
+    pub effective_at: i64,
+    pub update: PolicyUpdate,
+}
+
+impl PendingPolicyUpdate {
+    pub const LEN: usize = 8 + 1 + 1 + 32 + 8 + 8 + Policy::JSON_RULE_MAX_LEN + 1000 /* with padding */;
+    pub const SEED: &'static str = "pending_policy_update";
+}
+
+#[account]
+#[derive(Default, Serialize, Deserialize)]
+pub struct RuleChunk {
+    pub version: u8,
+    pub bump: [u8; 1],
+    pub policy: Pubkey,
+    pub index: u8,
+    pub json_rule: Vec<u8>, // utf-8 json, written in pieces with write_rule_chunk
+}
+
+impl RuleChunk {
+    pub const SEED: &'static str = "rule_chunk";
+    pub const MAX_LEN: usize = 10240; // the max account size that a single realloc can reach from 0
+    pub const JSON_RULE_MAX_LEN: usize = RuleChunk::MAX_LEN - RuleChunk::space(0);
+
+    pub const fn space(json_rule_len: usize) -> usize {
+        8 + 1 + 1 + 32 + 1 + 4 + json_rule_len
+    }
+
+    pub fn load(policy: &Pubkey, index: u8, remaining_accounts: &[AccountInfo]) -> Result<Self> {
+        for account in remaining_accounts.iter().filter(|x| x.owner == &crate::id()) {
+            if let Ok(rule_chunk) = RuleChunk::try_deserialize(&mut &account.try_borrow_data()?[..]) {
+                if rule_chunk.policy == *policy && rule_chunk.index == index {
+                    return Ok(rule_chunk);
+                }
+            }
+        }
+        msg!("rule chunk {} not found in remaining accounts", index);
+        Err(OCPErrorCode::AccountNotFound.into())
+    }
+
+    // the json_rule parses the same way as in evaluate_json_rule, checked before the policy references the chunk
+    pub fn valid(&self) -> Result<()> {
+        let is_valid = serde_json::from_slice::<JsonRule>(&self.json_rule).is_ok_and(|rule| {
+            CompiledRule::compile(&rule).is_some() || (rule.compile_events().is_some() && serde_json::from_slice::<Rule>(&self.json_rule).is_ok())
+        });
+        if !is_valid {
+            msg!("rule chunk {} is not a complete json_rule", self.index);
+            return Err(OCPErrorCode::InvalidRuleChunk.into());
+        }
+        Ok(())
+    }
+
+    pub fn evaluate(&self, ctx: &ActionCtx, remaining_accounts: &[AccountInfo]) -> Result<Vec<RuleEvent>> {
+        let (status, events, error) = evaluate_json_rule(&self.json_rule, ctx, remaining_accounts).ok_or(OCPErrorCode::InvalidRuleChunk)?;
+        if status != Status::Met {
+            msg!("Rule chunk {} does not match", self.index);
+        }
+        get_matched_events(status, &events, error.as_ref(), ctx)
+    }
+}
+
+#[account]
+#[derive(Default, Serialize, Deserialize)]
+pub struct RuleCounter {
+    pub version: u8,
+    pub bump: [u8; 1],
+    pub policy: Pubkey,
+    pub index: u8,
+    pub count: u64, // incremented by the increment_counter rule events
+}
+
+impl RuleCounter {
+    pub const LEN: usize = 8 + 1 + 1 + 32 + 1 + 8;
+    pub const SEED: &'static str = "rule_counter";
+
+    // the writable counter of the policy at the index, in the remaining_accounts
+    pub fn load<'a, 'info>(policy: &Pubkey, index: u8, remaining_accounts: &'a [AccountInfo<'info>]) -> Result<(&'a AccountInfo<'info>, Self)> {
+        for account in remaining_accounts.iter().filter(|x| x.owner == &crate::id() && x.is_writable) {
+            if let Ok(rule_counter) = RuleCounter::try_deserialize(&mut &account.try_borrow_data()?[..]) {
+                if rule_counter.policy == *policy && rule_counter.index == index {
+                    return Ok((account, rule_counter));
+                }
+            }
+        }
+        msg!("rule counter {} not found in remaining accounts", index);
+        Err(OCPErrorCode::AccountNotFound.into())
+    }
+}
+
+// the mint states of a policy. It's kept out of the policy, so that wrap, burn and migrate_to_mpl don't write the policy
+#[account]
+#[derive(Default, Serialize, Deserialize)]
+pub struct MintCounter {
+    pub version: u8,
+    pub bump: [u8; 1],
+    pub policy: Pubkey,
+    pub count: u64,
+}
+
+impl MintCounter {
+    pub const LEN: usize = 8 + 1 + 1 + 32 + 8;
+    pub const SEED: &'static str = "mint_counter";
+
+    // the writable mint counter of the policy, in the remaining_accounts
+    pub fn load<'a, 'info>(policy: &Pubkey, remaining_accounts: &'a [AccountInfo<'info>]) -> Result<(&'a AccountInfo<'info>, Self)> {
+        for account in remaining_accounts.iter().filter(|x| x.owner == &crate::id() && x.is_writable) {
+            if let Ok(mint_counter) = MintCounter::try_deserialize(&mut &account.try_borrow_data()?[..]) {
+                if mint_counter.policy == *policy {
+                    return Ok((account, mint_counter));
+                }
+            }
+        }
+        msg!("mint counter not found in remaining accounts");
+        Err(OCPErrorCode::AccountNotFound.into())
+    }
+
+    // wrap adds a mint state, burn and migrate_to_mpl remove one. init_policy creates the mint counter, and the version 0
+    // policies, which have none, are not counted
+    pub fn record(policy: &Account<Policy>, added: bool, remaining_accounts: &[AccountInfo]) -> Result<()> {
+        if !policy.is_counted() {
+            return Ok(());
+        }
+        let (account, mut mint_counter) = MintCounter::load(&policy.key(), remaining_accounts)?;
+        mint_counter.count = match added {
+            true => mint_counter.count.checked_add(1).ok_or(OCPErrorCode::NumericalOverflow)?,
+            false => mint_counter.count.saturating_sub(1),
+        };
+        mint_counter.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])
+    }
+}
+
+#[account]
+#[derive(Default, Serialize, Deserialize)]
+pub struct ListAccount {
+    pub version: u8,
+    pub bump: [u8; 1],
+    pub uuid: Pubkey,
+    pub authority: Pubkey,
+    pub keys: Vec<Pubkey>, // sorted and unique, so that it can be binary searched
+}
+
+impl ListAccount {
+    pub const SEED: &'static str = "list";
+    pub const MAX_KEYS: usize = 512; // the keys are deserialized on the heap when the list is extended or shrunk
+    const AUTHORITY_OFFSET: usize = 8 + 1 + 1 + 32;
+    const KEYS_OFFSET: usize = ListAccount::AUTHORITY_OFFSET + 32 + 4;
+
+    pub const fn space(num_keys: usize) -> usize {
+        ListAccount::KEYS_OFFSET + 32 * num_keys
+    }
+
+    pub fn valid(&self) -> Result<()> {
+        if self.keys.len() > ListAccount::MAX_KEYS {
+            msg!("list can have up to {} keys", ListAccount::MAX_KEYS);
+            return Err(OCPErrorCode::InvalidListAccount.into());
+        }
+        if self.keys.windows(2).any(|w| w[0] >= w[1]) {
+            msg!("list keys must be sorted and unique");
+            return Err(OCPErrorCode::InvalidListAccount.into());
+        }
+        Ok(())
+    }
+
+    // the list is in accounts, and its authority is the default pubkey, see freeze_list
+    pub fn is_frozen(list: &Pubkey, accounts: &[AccountInfo]) -> bool {
+        accounts
+            .iter()
+            .find(|x| x.key == list && x.owner == &crate::id())
+            .and_then(|x| x.try_borrow_data().ok())
+            .is_some_and(|data| {
+                data.len() >= ListAccount::KEYS_OFFSET
+                    && data[..8] == ListAccount::DISCRIMINATOR
+                    && data[ListAccount::AUTHORITY_OFFSET..ListAccount::AUTHORITY_OFFSET + 32] == Pubkey::default().to_bytes()
+            })
+    }
+
+    // binary search the list in accounts without deserializing the keys, None if the list is not found.
+    // With frozen_only, a list that its authority can still change is treated as not found
+    pub fn contains(list: &Pubkey, key: &Pubkey, accounts: &[AccountInfo], frozen_only: bool) -> Option<bool> {
+        let account = accounts.iter().find(|x| x.key == list && x.owner == &crate::id())?;
+        let data = account.try_borrow_data().ok()?;
+        if data.len() < ListAccount::KEYS_OFFSET || data[..8] != ListAccount::DISCRIMINATOR {
+            return None;
+        }
+        if frozen_only && data[ListAccount::AUTHORITY_OFFSET..ListAccount::AUTHORITY_OFFSET + 32] != Pubkey::default().to_bytes() {
+            return None;
+        }
+        let num_keys = u32::from_le_bytes(data[ListAccount::KEYS_OFFSET - 4..ListAccount::KEYS_OFFSET].try_into().ok()?) as usize;
+        let keys = data.get(ListAccount::KEYS_OFFSET..ListAccount::space(num_keys))?;
+
+        // search by index over the raw data, the heap is too small to collect the keys
+        let (mut low, mut high) = (0, num_keys);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            match keys[mid * 32..(mid + 1) * 32].cmp(key.as_ref()) {
+                std::cmp::Ordering::Equal => return Some(true),
+                std::cmp::Ordering::Less => low = mid + 1,
+                std::cmp::Ordering::Greater => high = mid,
+            }
+        }
+        Some(false)
+    }
+}
+
+#[account]
+#[derive(Default, Serialize, Deserialize)]
+pub struct RoyaltyReceipt {
+    pub version: u8,
+    pub bump: [u8; 1],
+    pub mint: Pubkey,
+    pub sequence: u64, // the mint_state.sale_count before the sale
+    pub payer: Pubkey,
+    pub price: u64,
+    pub price_mint: Option<Pubkey>, // None for SOL
+    pub royalty_bp: u16,
+    pub royalty_amount: u64,
+    pub paid_at: i64,
+}
+
+impl RoyaltyReceipt {
+    pub const LEN: usize = 8 + 1 + 1 + 32 + 8 + 32 + 8 + 33 + 2 + 8 + 8;
+    pub const SEED: &'static str = "royalty_receipt";
+}
+
+#[account]
+#[derive(Default, Serialize, Deserialize)]
+pub struct RoyaltyExemption {
+    pub version: u8,
+    pub bump: [u8; 1],
+    pub policy: Pubkey,
+    pub wallets: Vec<Pubkey>,     // exempted when it's the from or the to of a transfer
+    pub program_ids: Vec<Pubkey>, // exempted when it's the program calling into ocp
+}
+
+impl RoyaltyExemption {
+    pub const LEN: usize = 8 + 1 + 1 + 32 + (4 + 32 * RoyaltyExemption::MAX_ENTRIES) * 2;
+    pub const SEED: &'static str = "royalty_exemption";
+    pub const MAX_ENTRIES: usize = 32;
+
+    pub fn valid(&self) -> Result<()> {
+        if self.wallets.len() > RoyaltyExemption::MAX_ENTRIES || self.program_ids.len() > RoyaltyExemption::MAX_ENTRIES {
+            msg!(
+                "royalty exemption can have up to {} wallets and {} program_ids",
+                RoyaltyExemption::MAX_ENTRIES,
+                RoyaltyExemption::MAX_ENTRIES
+            );
+            return Err(OCPErrorCode::InvalidRoyaltyExemption.into());
+        }
+        Ok(())
+    }
+
+    pub fn is_exempted(&self, from: Option<&Pubkey>, to: Option<&Pubkey>, calling_program: Option<&Pubkey>) -> bool {
+        from.is_some_and(|x| self.wallets.contains(x))
+            || to.is_some_and(|x| self.wallets.contains(x))
+            || calling_program.is_some_and(|x| self.program_ids.contains(x))
+    }
+}
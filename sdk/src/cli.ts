@@ -8,13 +8,17 @@ import {
   createInitPolicyInstruction,
   createMigrateToMplInstruction,
   createUpdatePolicyInstruction,
+  Policy,
 } from "./generated";
 import {
   CMT_PROGRAM,
   computeBudgetIx,
+  createInitPolicyArgStruct,
   findFreezeAuthorityPk,
+  findMintCounterPk,
   findMintStatePk,
   findPolicyPk,
+  mintCounterAccountMeta,
   parsePriceLinearDynamicRoyaltyStruct,
   process_tx,
 } from "./pda";
@@ -69,17 +73,19 @@ const conn = new Connection(CLI_RPC, "confirmed");
 
 async function create_policy() {
   const uuid = Keypair.generate().publicKey;
+  const policy = findPolicyPk(uuid);
   const ix = createInitPolicyInstruction(
     {
-      policy: findPolicyPk(uuid),
+      policy,
+      mintCounter: findMintCounterPk(policy),
       authority: CLI_AUTHORITY.publicKey,
       uuid,
     },
     {
-      arg: {
+      arg: createInitPolicyArgStruct({
         jsonRule: CLI_JSON_RULE,
         dynamicRoyalty: CLI_DYNAMIC_ROYALTY_PRICE_LINEAR,
-      },
+      }),
     }
   );
   await process_tx(conn, [ix], [CLI_AUTHORITY]);
@@ -88,6 +94,8 @@ async function create_policy() {
 }
 
 async function update_policy() {
+  // update_policy overwrites every field, so the ones that the cli doesn't set are kept as they are
+  const policy = await Policy.fromAccountAddress(conn, CLI_POLICY_PUBKEY);
   const ix = createUpdatePolicyInstruction(
     { policy: CLI_POLICY_PUBKEY, authority: CLI_AUTHORITY.publicKey },
     {
@@ -95,6 +103,14 @@ async function update_policy() {
         authority: CLI_AUTHORITY.publicKey,
         jsonRule: CLI_JSON_RULE,
         dynamicRoyalty: CLI_DYNAMIC_ROYALTY_PRICE_LINEAR,
+        royaltySplit: policy.royaltySplit,
+        royaltyEnforcementMode: policy.royaltyEnforcementMode,
+        ruleChunkCount: policy.ruleChunkCount,
+        actionRules: policy.actionRules.map(({ action, jsonRule }) => ({
+          action,
+          jsonRule,
+        })),
+        updateDelay: policy.updateDelay,
       },
     }
   );
@@ -119,6 +135,7 @@ async function migrate_to_mpl() {
     metadataProgram: TokenMetadataProgram.publicKey,
     payer: CLI_UPDATE_AUTHORITY.publicKey,
   });
+  ix.keys.push(mintCounterAccountMeta(CLI_POLICY_PUBKEY));
   await process_tx(conn, [computeBudgetIx, ix], [CLI_UPDATE_AUTHORITY]);
   console.log("migrated to mpl, mint: ", CLI_MINT.toBase58());
 }
@@ -23,6 +23,7 @@ export type MintStateArgs = {
   lastApprovedAt: beet.bignum
   lastTransferredAt: beet.bignum
   transferredCount: number
+  saleCount: beet.bignum
 }
 
 export const mintStateDiscriminator = [81, 17, 143, 120, 23, 57, 22, 117]
@@ -42,7 +43,8 @@ export class MintState implements MintStateArgs {
     readonly lockedBy: beet.COption<web3.PublicKey>,
     readonly lastApprovedAt: beet.bignum,
     readonly lastTransferredAt: beet.bignum,
-    readonly transferredCount: number
+    readonly transferredCount: number,
+    readonly saleCount: beet.bignum
   ) {}
 
   /**
@@ -57,7 +59,8 @@ export class MintState implements MintStateArgs {
       args.lockedBy,
       args.lastApprovedAt,
       args.lastTransferredAt,
-      args.transferredCount
+      args.transferredCount,
+      args.saleCount
     )
   }
 
@@ -190,6 +193,17 @@ export class MintState implements MintStateArgs {
         return x
       })(),
       transferredCount: this.transferredCount,
+      saleCount: (() => {
+        const x = <{ toNumber: () => number }>this.saleCount
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
     }
   }
 }
@@ -214,6 +228,7 @@ export const mintStateBeet = new beet.FixableBeetStruct<
     ['lastApprovedAt', beet.i64],
     ['lastTransferredAt', beet.i64],
     ['transferredCount', beet.u32],
+    ['saleCount', beet.u64],
   ],
   MintState.fromArgs,
   'MintState'
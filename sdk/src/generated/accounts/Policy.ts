@@ -9,6 +9,12 @@ import * as web3 from '@solana/web3.js'
 import * as beet from '@metaplex-foundation/beet'
 import * as beetSolana from '@metaplex-foundation/beet-solana'
 import { DynamicRoyalty, dynamicRoyaltyBeet } from '../types/DynamicRoyalty'
+import {
+  RoyaltyRecipient,
+  royaltyRecipientBeet,
+} from '../types/RoyaltyRecipient'
+import { CompiledRule, compiledRuleBeet } from '../types/CompiledRule'
+import { ActionRule, actionRuleBeet } from '../types/ActionRule'
 
 /**
  * Arguments used to create {@link Policy}
@@ -22,6 +28,21 @@ export type PolicyArgs = {
   authority: web3.PublicKey
   dynamicRoyalty: beet.COption<DynamicRoyalty>
   jsonRule: beet.COption<string>
+  royaltySplit: beet.COption<RoyaltyRecipient[]>
+  royaltyEnforcementMode: number
+  compiledRule: beet.COption<CompiledRule>
+  ruleChunkCount: number
+  actionRules: ActionRule[]
+  updateDelay: beet.bignum
+  pendingAuthority: beet.COption<web3.PublicKey>
+  ruleAdmin: beet.COption<web3.PublicKey>
+  royaltyAdmin: beet.COption<web3.PublicKey>
+  pauseGuardian: beet.COption<web3.PublicKey>
+  unmanaged: boolean
+  frozen: boolean
+  authorityFrozen: boolean
+  accountCount: number
+  paused: boolean
 }
 
 export const policyDiscriminator = [222, 135, 7, 163, 235, 177, 33, 68]
@@ -39,7 +60,22 @@ export class Policy implements PolicyArgs {
     readonly uuid: web3.PublicKey,
     readonly authority: web3.PublicKey,
     readonly dynamicRoyalty: beet.COption<DynamicRoyalty>,
-    readonly jsonRule: beet.COption<string>
+    readonly jsonRule: beet.COption<string>,
+    readonly royaltySplit: beet.COption<RoyaltyRecipient[]>,
+    readonly royaltyEnforcementMode: number,
+    readonly compiledRule: beet.COption<CompiledRule>,
+    readonly ruleChunkCount: number,
+    readonly actionRules: ActionRule[],
+    readonly updateDelay: beet.bignum,
+    readonly pendingAuthority: beet.COption<web3.PublicKey>,
+    readonly ruleAdmin: beet.COption<web3.PublicKey>,
+    readonly royaltyAdmin: beet.COption<web3.PublicKey>,
+    readonly pauseGuardian: beet.COption<web3.PublicKey>,
+    readonly unmanaged: boolean,
+    readonly frozen: boolean,
+    readonly authorityFrozen: boolean,
+    readonly accountCount: number,
+    readonly paused: boolean
   ) {}
 
   /**
@@ -52,7 +88,22 @@ export class Policy implements PolicyArgs {
       args.uuid,
       args.authority,
       args.dynamicRoyalty,
-      args.jsonRule
+      args.jsonRule,
+      args.royaltySplit,
+      args.royaltyEnforcementMode,
+      args.compiledRule,
+      args.ruleChunkCount,
+      args.actionRules,
+      args.updateDelay,
+      args.pendingAuthority,
+      args.ruleAdmin,
+      args.royaltyAdmin,
+      args.pauseGuardian,
+      args.unmanaged,
+      args.frozen,
+      args.authorityFrozen,
+      args.accountCount,
+      args.paused
     )
   }
 
@@ -163,6 +214,31 @@ export class Policy implements PolicyArgs {
       authority: this.authority.toBase58(),
       dynamicRoyalty: this.dynamicRoyalty,
       jsonRule: this.jsonRule,
+      royaltySplit: this.royaltySplit,
+      royaltyEnforcementMode: this.royaltyEnforcementMode,
+      compiledRule: this.compiledRule,
+      ruleChunkCount: this.ruleChunkCount,
+      actionRules: this.actionRules,
+      updateDelay: (() => {
+        const x = <{ toNumber: () => number }>this.updateDelay
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
+      pendingAuthority: this.pendingAuthority,
+      ruleAdmin: this.ruleAdmin,
+      royaltyAdmin: this.royaltyAdmin,
+      pauseGuardian: this.pauseGuardian,
+      unmanaged: this.unmanaged,
+      frozen: this.frozen,
+      authorityFrozen: this.authorityFrozen,
+      accountCount: this.accountCount,
+      paused: this.paused,
     }
   }
 }
@@ -185,6 +261,21 @@ export const policyBeet = new beet.FixableBeetStruct<
     ['authority', beetSolana.publicKey],
     ['dynamicRoyalty', beet.coption(dynamicRoyaltyBeet)],
     ['jsonRule', beet.coption(beet.utf8String)],
+    ['royaltySplit', beet.coption(beet.array(royaltyRecipientBeet))],
+    ['royaltyEnforcementMode', beet.u8],
+    ['compiledRule', beet.coption(compiledRuleBeet)],
+    ['ruleChunkCount', beet.u8],
+    ['actionRules', beet.array(actionRuleBeet)],
+    ['updateDelay', beet.i64],
+    ['pendingAuthority', beet.coption(beetSolana.publicKey)],
+    ['ruleAdmin', beet.coption(beetSolana.publicKey)],
+    ['royaltyAdmin', beet.coption(beetSolana.publicKey)],
+    ['pauseGuardian', beet.coption(beetSolana.publicKey)],
+    ['unmanaged', beet.bool],
+    ['frozen', beet.bool],
+    ['authorityFrozen', beet.bool],
+    ['accountCount', beet.u32],
+    ['paused', beet.bool],
   ],
   Policy.fromArgs,
   'Policy'
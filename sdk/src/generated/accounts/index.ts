@@ -1,7 +1,34 @@
+export * from './ListAccount'
+export * from './MintCounter'
 export * from './MintState'
+export * from './PendingPolicyUpdate'
 export * from './Policy'
+export * from './ProgramConfig'
+export * from './RoyaltyExemption'
+export * from './RoyaltyReceipt'
+export * from './RuleChunk'
+export * from './RuleCounter'
 
+import { ListAccount } from './ListAccount'
+import { MintCounter } from './MintCounter'
 import { MintState } from './MintState'
+import { PendingPolicyUpdate } from './PendingPolicyUpdate'
 import { Policy } from './Policy'
+import { ProgramConfig } from './ProgramConfig'
+import { RoyaltyExemption } from './RoyaltyExemption'
+import { RoyaltyReceipt } from './RoyaltyReceipt'
+import { RuleChunk } from './RuleChunk'
+import { RuleCounter } from './RuleCounter'
 
-export const accountProviders = { MintState, Policy }
+export const accountProviders = {
+  ListAccount,
+  MintCounter,
+  MintState,
+  PendingPolicyUpdate,
+  Policy,
+  ProgramConfig,
+  RoyaltyExemption,
+  RoyaltyReceipt,
+  RuleChunk,
+  RuleCounter,
+}
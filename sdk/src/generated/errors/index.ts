@@ -353,6 +353,362 @@ createErrorFromNameLookup.set(
   () => new InvalidMetadataUpdateAuthorityError()
 )
 
+/**
+ * InvalidRoyaltyRecipient: 'Invalid royalty recipient'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidRoyaltyRecipientError extends Error {
+  readonly code: number = 0x177f
+  readonly name: string = 'InvalidRoyaltyRecipient'
+  constructor() {
+    super('Invalid royalty recipient')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidRoyaltyRecipientError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x177f, () => new InvalidRoyaltyRecipientError())
+createErrorFromNameLookup.set(
+  'InvalidRoyaltyRecipient',
+  () => new InvalidRoyaltyRecipientError()
+)
+
+/**
+ * InvalidPriceMint: 'Invalid price mint'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidPriceMintError extends Error {
+  readonly code: number = 0x1780
+  readonly name: string = 'InvalidPriceMint'
+  constructor() {
+    super('Invalid price mint')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidPriceMintError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1780, () => new InvalidPriceMintError())
+createErrorFromNameLookup.set(
+  'InvalidPriceMint',
+  () => new InvalidPriceMintError()
+)
+
+/**
+ * InvalidRoyaltySplit: 'Invalid royalty split'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidRoyaltySplitError extends Error {
+  readonly code: number = 0x1781
+  readonly name: string = 'InvalidRoyaltySplit'
+  constructor() {
+    super('Invalid royalty split')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidRoyaltySplitError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1781, () => new InvalidRoyaltySplitError())
+createErrorFromNameLookup.set(
+  'InvalidRoyaltySplit',
+  () => new InvalidRoyaltySplitError()
+)
+
+/**
+ * InvalidRoyaltyExemption: 'Invalid royalty exemption'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidRoyaltyExemptionError extends Error {
+  readonly code: number = 0x1782
+  readonly name: string = 'InvalidRoyaltyExemption'
+  constructor() {
+    super('Invalid royalty exemption')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidRoyaltyExemptionError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1782, () => new InvalidRoyaltyExemptionError())
+createErrorFromNameLookup.set(
+  'InvalidRoyaltyExemption',
+  () => new InvalidRoyaltyExemptionError()
+)
+
+/**
+ * InvalidRoyaltyPaymentMemo: 'Invalid royalty payment memo'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidRoyaltyPaymentMemoError extends Error {
+  readonly code: number = 0x1783
+  readonly name: string = 'InvalidRoyaltyPaymentMemo'
+  constructor() {
+    super('Invalid royalty payment memo')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidRoyaltyPaymentMemoError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x1783,
+  () => new InvalidRoyaltyPaymentMemoError()
+)
+createErrorFromNameLookup.set(
+  'InvalidRoyaltyPaymentMemo',
+  () => new InvalidRoyaltyPaymentMemoError()
+)
+
+/**
+ * RoyaltyNotPaid: 'Royalty not paid'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RoyaltyNotPaidError extends Error {
+  readonly code: number = 0x1784
+  readonly name: string = 'RoyaltyNotPaid'
+  constructor() {
+    super('Royalty not paid')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RoyaltyNotPaidError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1784, () => new RoyaltyNotPaidError())
+createErrorFromNameLookup.set('RoyaltyNotPaid', () => new RoyaltyNotPaidError())
+
+/**
+ * InvalidRuleChunk: 'Invalid rule chunk'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidRuleChunkError extends Error {
+  readonly code: number = 0x1785
+  readonly name: string = 'InvalidRuleChunk'
+  constructor() {
+    super('Invalid rule chunk')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidRuleChunkError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1785, () => new InvalidRuleChunkError())
+createErrorFromNameLookup.set(
+  'InvalidRuleChunk',
+  () => new InvalidRuleChunkError()
+)
+
+/**
+ * InvalidListAccount: 'Invalid list account'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidListAccountError extends Error {
+  readonly code: number = 0x1786
+  readonly name: string = 'InvalidListAccount'
+  constructor() {
+    super('Invalid list account')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidListAccountError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1786, () => new InvalidListAccountError())
+createErrorFromNameLookup.set(
+  'InvalidListAccount',
+  () => new InvalidListAccountError()
+)
+
+/**
+ * MemoRequired: 'Memo required'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MemoRequiredError extends Error {
+  readonly code: number = 0x1787
+  readonly name: string = 'MemoRequired'
+  constructor() {
+    super('Memo required')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MemoRequiredError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1787, () => new MemoRequiredError())
+createErrorFromNameLookup.set('MemoRequired', () => new MemoRequiredError())
+
+/**
+ * InvalidRuleEvent: 'Invalid rule event'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidRuleEventError extends Error {
+  readonly code: number = 0x1788
+  readonly name: string = 'InvalidRuleEvent'
+  constructor() {
+    super('Invalid rule event')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidRuleEventError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1788, () => new InvalidRuleEventError())
+createErrorFromNameLookup.set(
+  'InvalidRuleEvent',
+  () => new InvalidRuleEventError()
+)
+
+/**
+ * PolicyUpdateTimelocked: 'Policy update is timelocked'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PolicyUpdateTimelockedError extends Error {
+  readonly code: number = 0x1789
+  readonly name: string = 'PolicyUpdateTimelocked'
+  constructor() {
+    super('Policy update is timelocked')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PolicyUpdateTimelockedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1789, () => new PolicyUpdateTimelockedError())
+createErrorFromNameLookup.set(
+  'PolicyUpdateTimelocked',
+  () => new PolicyUpdateTimelockedError()
+)
+
+/**
+ * PolicyUpdateNotEffective: 'Policy update is not effective yet'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PolicyUpdateNotEffectiveError extends Error {
+  readonly code: number = 0x178a
+  readonly name: string = 'PolicyUpdateNotEffective'
+  constructor() {
+    super('Policy update is not effective yet')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PolicyUpdateNotEffectiveError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178a, () => new PolicyUpdateNotEffectiveError())
+createErrorFromNameLookup.set(
+  'PolicyUpdateNotEffective',
+  () => new PolicyUpdateNotEffectiveError()
+)
+
+/**
+ * InvalidProgramConfig: 'Invalid program config'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidProgramConfigError extends Error {
+  readonly code: number = 0x178b
+  readonly name: string = 'InvalidProgramConfig'
+  constructor() {
+    super('Invalid program config')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidProgramConfigError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178b, () => new InvalidProgramConfigError())
+createErrorFromNameLookup.set(
+  'InvalidProgramConfig',
+  () => new InvalidProgramConfigError()
+)
+
+/**
+ * PolicyFrozen: 'Policy is frozen'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PolicyFrozenError extends Error {
+  readonly code: number = 0x178c
+  readonly name: string = 'PolicyFrozen'
+  constructor() {
+    super('Policy is frozen')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PolicyFrozenError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178c, () => new PolicyFrozenError())
+createErrorFromNameLookup.set('PolicyFrozen', () => new PolicyFrozenError())
+
+/**
+ * PolicyInUse: 'Policy is still in use'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PolicyInUseError extends Error {
+  readonly code: number = 0x178d
+  readonly name: string = 'PolicyInUse'
+  constructor() {
+    super('Policy is still in use')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PolicyInUseError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178d, () => new PolicyInUseError())
+createErrorFromNameLookup.set('PolicyInUse', () => new PolicyInUseError())
+
+/**
+ * PolicyPaused: 'Policy is paused'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PolicyPausedError extends Error {
+  readonly code: number = 0x178e
+  readonly name: string = 'PolicyPaused'
+  constructor() {
+    super('Policy is paused')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PolicyPausedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178e, () => new PolicyPausedError())
+createErrorFromNameLookup.set('PolicyPaused', () => new PolicyPausedError())
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
@@ -1,13 +1,34 @@
+export * from './acceptPolicyAuthority'
+export * from './applyPolicyUpdate'
 export * from './approve'
 export * from './burn'
+export * from './cancelPolicyUpdate'
 export * from './close'
+export * from './closePolicy'
+export * from './extendList'
+export * from './freezeList'
+export * from './freezePolicy'
+export * from './getRoyaltyQuote'
 export * from './initAccount'
+export * from './initList'
 export * from './initPolicy'
+export * from './initRuleChunk'
+export * from './initRuleCounter'
 export * from './lock'
 export * from './migrateToMpl'
 export * from './mintTo'
+export * from './nominatePolicyAuthority'
+export * from './proposePolicyUpdate'
 export * from './revoke'
+export * from './setPolicyPaused'
+export * from './setPolicyRoles'
+export * from './setProgramConfig'
+export * from './setRoyaltyExemption'
+export * from './shrinkList'
+export * from './simulatePolicy'
 export * from './transfer'
+export * from './transferWithPayment'
 export * from './unlock'
 export * from './updatePolicy'
 export * from './wrap'
+export * from './writeRuleChunk'
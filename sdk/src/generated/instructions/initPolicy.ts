@@ -37,6 +37,7 @@ export const initPolicyStruct = new beet.FixableBeetArgsStruct<
  * Accounts required by the _initPolicy_ instruction
  *
  * @property [_writable_] policy
+ * @property [_writable_] mintCounter
  * @property [] uuid
  * @property [_writable_, **signer**] authority
  * @category Instructions
@@ -45,6 +46,7 @@ export const initPolicyStruct = new beet.FixableBeetArgsStruct<
  */
 export type InitPolicyInstructionAccounts = {
   policy: web3.PublicKey
+  mintCounter: web3.PublicKey
   uuid: web3.PublicKey
   authority: web3.PublicKey
   systemProgram?: web3.PublicKey
@@ -79,6 +81,11 @@ export function createInitPolicyInstruction(
       isWritable: true,
       isSigner: false,
     },
+    {
+      pubkey: accounts.mintCounter,
+      isWritable: true,
+      isSigner: false,
+    },
     {
       pubkey: accounts.uuid,
       isWritable: false,
@@ -34,6 +34,7 @@ export const transferStruct = new beet.BeetArgsStruct<{
  * @property [_writable_] toAccount
  * @property [] cmtProgram
  * @property [] instructions
+ * @property [] royaltyExemption (optional)
  * @category Instructions
  * @category Transfer
  * @category generated
@@ -51,6 +52,7 @@ export type TransferInstructionAccounts = {
   tokenProgram?: web3.PublicKey
   cmtProgram: web3.PublicKey
   instructions: web3.PublicKey
+  royaltyExemption?: web3.PublicKey
 }
 
 export const transferInstructionDiscriminator = [
@@ -133,6 +135,11 @@ export function createTransferInstruction(
       isWritable: false,
       isSigner: false,
     },
+    {
+      pubkey: accounts.royaltyExemption ?? programId,
+      isWritable: false,
+      isSigner: false,
+    },
   ]
 
   const ix = new web3.TransactionInstruction({
@@ -37,7 +37,8 @@ export const updatePolicyStruct = new beet.FixableBeetArgsStruct<
  * Accounts required by the _updatePolicy_ instruction
  *
  * @property [_writable_] policy
- * @property [**signer**] authority
+ * @property [_writable_, **signer**] authority
+ * @property [] programConfig (optional)
  * @category Instructions
  * @category UpdatePolicy
  * @category generated
@@ -46,6 +47,7 @@ export type UpdatePolicyInstructionAccounts = {
   policy: web3.PublicKey
   authority: web3.PublicKey
   systemProgram?: web3.PublicKey
+  programConfig?: web3.PublicKey
 }
 
 export const updatePolicyInstructionDiscriminator = [
@@ -79,7 +81,7 @@ export function createUpdatePolicyInstruction(
     },
     {
       pubkey: accounts.authority,
-      isWritable: false,
+      isWritable: true,
       isSigner: true,
     },
     {
@@ -87,6 +89,11 @@ export function createUpdatePolicyInstruction(
       isWritable: false,
       isSigner: false,
     },
+    {
+      pubkey: accounts.programConfig ?? programId,
+      isWritable: false,
+      isSigner: false,
+    },
   ]
 
   const ix = new web3.TransactionInstruction({
@@ -10,12 +10,30 @@ import {
   DynamicRoyaltyPriceLinear,
   dynamicRoyaltyPriceLinearBeet,
 } from './DynamicRoyaltyPriceLinear'
+import {
+  DynamicRoyaltyPricePiecewiseLinear,
+  dynamicRoyaltyPricePiecewiseLinearBeet,
+} from './DynamicRoyaltyPricePiecewiseLinear'
+import {
+  DynamicRoyaltyHoldingTimeLinear,
+  dynamicRoyaltyHoldingTimeLinearBeet,
+} from './DynamicRoyaltyHoldingTimeLinear'
+import {
+  DynamicRoyaltyHolderDiscount,
+  dynamicRoyaltyHolderDiscountBeet,
+} from './DynamicRoyaltyHolderDiscount'
 export type DynamicRoyalty = {
   version: number
   kind: number
   overrideRoyaltyBp: beet.COption<number>
   kindPriceLinear: beet.COption<DynamicRoyaltyPriceLinear>
-  reserved0: number[] /* size: 32 */
+  kindPricePiecewiseLinear: beet.COption<DynamicRoyaltyPricePiecewiseLinear>
+  kindHoldingTimeLinear: beet.COption<DynamicRoyaltyHoldingTimeLinear>
+  kindHolderDiscount: beet.COption<DynamicRoyaltyHolderDiscount>
+  minRoyaltyBp: beet.COption<number>
+  maxRoyaltyBp: beet.COption<number>
+  minRoyaltyAmount: beet.COption<beet.bignum>
+  reserved0: number[] /* size: 26 */
   reserved1: number[] /* size: 32 */
   reserved2: number[] /* size: 32 */
   reserved3: number[] /* size: 32 */
@@ -32,7 +50,19 @@ export const dynamicRoyaltyBeet =
       ['kind', beet.u8],
       ['overrideRoyaltyBp', beet.coption(beet.u16)],
       ['kindPriceLinear', beet.coption(dynamicRoyaltyPriceLinearBeet)],
-      ['reserved0', beet.uniformFixedSizeArray(beet.u8, 32)],
+      [
+        'kindPricePiecewiseLinear',
+        beet.coption(dynamicRoyaltyPricePiecewiseLinearBeet),
+      ],
+      [
+        'kindHoldingTimeLinear',
+        beet.coption(dynamicRoyaltyHoldingTimeLinearBeet),
+      ],
+      ['kindHolderDiscount', beet.coption(dynamicRoyaltyHolderDiscountBeet)],
+      ['minRoyaltyBp', beet.coption(beet.u16)],
+      ['maxRoyaltyBp', beet.coption(beet.u16)],
+      ['minRoyaltyAmount', beet.coption(beet.u64)],
+      ['reserved0', beet.uniformFixedSizeArray(beet.u8, 26)],
       ['reserved1', beet.uniformFixedSizeArray(beet.u8, 32)],
       ['reserved2', beet.uniformFixedSizeArray(beet.u8, 32)],
       ['reserved3', beet.uniformFixedSizeArray(beet.u8, 32)],
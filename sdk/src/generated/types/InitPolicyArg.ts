@@ -7,9 +7,16 @@
 
 import * as beet from '@metaplex-foundation/beet'
 import { DynamicRoyalty, dynamicRoyaltyBeet } from './DynamicRoyalty'
+import { RoyaltyRecipient, royaltyRecipientBeet } from './RoyaltyRecipient'
+import { ActionRuleArg, actionRuleArgBeet } from './ActionRuleArg'
 export type InitPolicyArg = {
   jsonRule: beet.COption<string>
   dynamicRoyalty: beet.COption<DynamicRoyalty>
+  royaltySplit: beet.COption<RoyaltyRecipient[]>
+  royaltyEnforcementMode: number
+  ruleChunkCount: number
+  actionRules: ActionRuleArg[]
+  updateDelay: beet.bignum
 }
 
 /**
@@ -20,6 +27,11 @@ export const initPolicyArgBeet = new beet.FixableBeetArgsStruct<InitPolicyArg>(
   [
     ['jsonRule', beet.coption(beet.utf8String)],
     ['dynamicRoyalty', beet.coption(dynamicRoyaltyBeet)],
+    ['royaltySplit', beet.coption(beet.array(royaltyRecipientBeet))],
+    ['royaltyEnforcementMode', beet.u8],
+    ['ruleChunkCount', beet.u8],
+    ['actionRules', beet.array(actionRuleArgBeet)],
+    ['updateDelay', beet.i64],
   ],
   'InitPolicyArg'
 )
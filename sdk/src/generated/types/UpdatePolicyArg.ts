@@ -9,10 +9,17 @@ import * as web3 from '@solana/web3.js'
 import * as beet from '@metaplex-foundation/beet'
 import * as beetSolana from '@metaplex-foundation/beet-solana'
 import { DynamicRoyalty, dynamicRoyaltyBeet } from './DynamicRoyalty'
+import { RoyaltyRecipient, royaltyRecipientBeet } from './RoyaltyRecipient'
+import { ActionRuleArg, actionRuleArgBeet } from './ActionRuleArg'
 export type UpdatePolicyArg = {
   authority: web3.PublicKey
   jsonRule: beet.COption<string>
   dynamicRoyalty: beet.COption<DynamicRoyalty>
+  royaltySplit: beet.COption<RoyaltyRecipient[]>
+  royaltyEnforcementMode: number
+  ruleChunkCount: number
+  actionRules: ActionRuleArg[]
+  updateDelay: beet.bignum
 }
 
 /**
@@ -25,6 +32,11 @@ export const updatePolicyArgBeet =
       ['authority', beetSolana.publicKey],
       ['jsonRule', beet.coption(beet.utf8String)],
       ['dynamicRoyalty', beet.coption(dynamicRoyaltyBeet)],
+      ['royaltySplit', beet.coption(beet.array(royaltyRecipientBeet))],
+      ['royaltyEnforcementMode', beet.u8],
+      ['ruleChunkCount', beet.u8],
+      ['actionRules', beet.array(actionRuleArgBeet)],
+      ['updateDelay', beet.i64],
     ],
     'UpdatePolicyArg'
   )
@@ -1,4 +1,38 @@
+export * from './ActionRule'
+export * from './ActionRuleArg'
+export * from './CompiledCondition'
+export * from './CompiledConstraint'
+export * from './CompiledRule'
+export * from './ConditionError'
+export * from './ConditionTrace'
 export * from './DynamicRoyalty'
+export * from './DynamicRoyaltyHolderDiscount'
+export * from './DynamicRoyaltyHoldingTimeLinear'
 export * from './DynamicRoyaltyPriceLinear'
+export * from './DynamicRoyaltyPricePiecewiseLinear'
+export * from './DynamicRoyaltyPricePoint'
+export * from './ExtendListArg'
+export * from './Fact'
+export * from './FreezePolicyArg'
+export * from './GetRoyaltyQuoteArg'
 export * from './InitPolicyArg'
+export * from './InitRuleChunkArg'
+export * from './InitRuleCounterArg'
+export * from './MerkleProof'
+export * from './NominatePolicyAuthorityArg'
+export * from './PolicyTrace'
+export * from './PolicyUpdate'
+export * from './RoyaltyQuote'
+export * from './RoyaltyQuoteRecipient'
+export * from './RoyaltyRecipient'
+export * from './RuleEvent'
+export * from './RuleTrace'
+export * from './SetPolicyPausedArg'
+export * from './SetPolicyRolesArg'
+export * from './SetProgramConfigArg'
+export * from './SetRoyaltyExemptionArg'
+export * from './ShrinkListArg'
+export * from './SimulatePolicyArg'
+export * from './TransferWithPaymentArg'
 export * from './UpdatePolicyArg'
+export * from './WriteRuleChunkArg'
@@ -7,7 +7,12 @@ import {
   Transaction,
   TransactionInstruction,
 } from "@solana/web3.js";
-import { DynamicRoyalty, PROGRAM_ID } from "./generated";
+import {
+  DynamicRoyalty,
+  InitPolicyArg,
+  PROGRAM_ID,
+  UpdatePolicyArg,
+} from "./generated";
 import * as anchor from "@project-serum/anchor";
 
 export const LARGER_COMPUTE_UNIT = 1_400_000;
@@ -35,6 +40,85 @@ export const findFreezeAuthorityPk = (policy: PublicKey) => {
   return PublicKey.findProgramAddressSync([policy.toBuffer()], CMT_PROGRAM)[0];
 };
 
+export const findProgramConfigPk = () => {
+  return PublicKey.findProgramAddressSync(
+    [utils.bytes.utf8.encode("program_config")],
+    PROGRAM_ID
+  )[0];
+};
+
+export const findPendingPolicyUpdatePk = (policy: PublicKey) => {
+  return PublicKey.findProgramAddressSync(
+    [utils.bytes.utf8.encode("pending_policy_update"), policy.toBuffer()],
+    PROGRAM_ID
+  )[0];
+};
+
+export const findRuleChunkPk = (policy: PublicKey, index: number) => {
+  return PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode("rule_chunk"),
+      policy.toBuffer(),
+      Buffer.from([index]),
+    ],
+    PROGRAM_ID
+  )[0];
+};
+
+export const findRuleCounterPk = (policy: PublicKey, index: number) => {
+  return PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode("rule_counter"),
+      policy.toBuffer(),
+      Buffer.from([index]),
+    ],
+    PROGRAM_ID
+  )[0];
+};
+
+export const findMintCounterPk = (policy: PublicKey) => {
+  return PublicKey.findProgramAddressSync(
+    [utils.bytes.utf8.encode("mint_counter"), policy.toBuffer()],
+    PROGRAM_ID
+  )[0];
+};
+
+export const findListPk = (uuid: PublicKey) => {
+  return PublicKey.findProgramAddressSync(
+    [utils.bytes.utf8.encode("list"), uuid.toBuffer()],
+    PROGRAM_ID
+  )[0];
+};
+
+export const findRoyaltyExemptionPk = (policy: PublicKey) => {
+  return PublicKey.findProgramAddressSync(
+    [utils.bytes.utf8.encode("royalty_exemption"), policy.toBuffer()],
+    PROGRAM_ID
+  )[0];
+};
+
+// saleCount is the mint_state.sale_count before the sale
+export const findRoyaltyReceiptPk = (
+  mint: PublicKey,
+  saleCount: anchor.BN
+) => {
+  return PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode("royalty_receipt"),
+      mint.toBuffer(),
+      saleCount.toArrayLike(Buffer, "le", 8),
+    ],
+    PROGRAM_ID
+  )[0];
+};
+
+// wrap, burn and migrate_to_mpl count the mints of the policy in its mint counter
+export const mintCounterAccountMeta = (policy: PublicKey) => ({
+  pubkey: findMintCounterPk(policy),
+  isWritable: true,
+  isSigner: false,
+});
+
 export const createDynamicRoyaltyStruct = ({
   startPrice,
   endPrice,
@@ -57,7 +141,13 @@ export const createDynamicRoyaltyStruct = ({
       startMultiplierBp,
       endMultiplierBp,
     },
-    reserved0: new Array(32).fill(0),
+    kindPricePiecewiseLinear: null,
+    kindHoldingTimeLinear: null,
+    kindHolderDiscount: null,
+    minRoyaltyBp: null,
+    maxRoyaltyBp: null,
+    minRoyaltyAmount: null,
+    reserved0: new Array(26).fill(0),
     reserved1: new Array(32).fill(0),
     reserved2: new Array(32).fill(0),
     reserved3: new Array(32).fill(0),
@@ -65,6 +155,32 @@ export const createDynamicRoyaltyStruct = ({
   return dynamicRoyalty;
 };
 
+// the fields that are not given keep the init_policy defaults: no royalty override and no timelock
+export const createInitPolicyArgStruct = (
+  arg: Partial<InitPolicyArg>
+): InitPolicyArg => {
+  return {
+    jsonRule: null,
+    dynamicRoyalty: null,
+    royaltySplit: null,
+    royaltyEnforcementMode: 0,
+    ruleChunkCount: 0,
+    actionRules: [],
+    updateDelay: new anchor.BN(0),
+    ...arg,
+  };
+};
+
+// UpdatePolicyArg overwrites every field of the policy, and its authority must be the current policy authority
+export const createUpdatePolicyArgStruct = (
+  arg: Partial<UpdatePolicyArg> & { authority: PublicKey }
+): UpdatePolicyArg => {
+  return {
+    ...createInitPolicyArgStruct(arg),
+    ...arg,
+  };
+};
+
 export const parsePriceLinearDynamicRoyaltyStruct = (jsonStr: string) => {
   if (jsonStr === "" || jsonStr === "null") {
     return null;
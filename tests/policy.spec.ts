@@ -1,21 +1,72 @@
 import * as anchor from "@project-serum/anchor";
 import {
+  BPF_LOADER_UPGRADEABLE_PROGRAM_ID,
   Keypair,
   LAMPORTS_PER_SOL,
+  PublicKey,
   TransactionInstruction
 } from "@solana/web3.js";
 import { assert } from "chai";
 import {
+  createAcceptPolicyAuthorityInstruction,
+  createApplyPolicyUpdateInstruction,
+  createCancelPolicyUpdateInstruction,
+  createClosePolicyInstruction,
+  createExtendListInstruction,
+  createFreezeListInstruction,
+  createFreezePolicyInstruction,
+  createInitListInstruction,
   createInitPolicyInstruction,
+  createInitRuleChunkInstruction,
+  createInitRuleCounterInstruction,
+  createNominatePolicyAuthorityInstruction,
+  createProposePolicyUpdateInstruction,
+  createSetPolicyPausedInstruction,
+  createSetPolicyRolesInstruction,
+  createSetProgramConfigInstruction,
+  createSetRoyaltyExemptionInstruction,
+  createShrinkListInstruction,
   createUpdatePolicyInstruction,
-  Policy
+  createWriteRuleChunkInstruction,
+  InvalidAuthorityError,
+  InvalidListAccountError,
+  InvalidRuleChunkError,
+  ListAccount,
+  PendingPolicyUpdate,
+  Policy,
+  PolicyFrozenError,
+  PolicyInUseError,
+  PolicyPausedError,
+  PolicyUpdateNotEffectiveError,
+  PolicyUpdateTimelockedError,
+  PROGRAM_ID,
+  RoyaltyExemption,
+  RuleChunk,
+  RuleCounter
 } from "../sdk/src/generated";
 import {
   createDynamicRoyaltyStruct,
+  createInitPolicyArgStruct,
+  createUpdatePolicyArgStruct,
+  findListPk,
+  findMintCounterPk,
+  findPendingPolicyUpdatePk,
   findPolicyPk,
+  findProgramConfigPk,
+  findRoyaltyExemptionPk,
+  findRuleChunkPk,
+  findRuleCounterPk,
   process_tx
 } from "../sdk/src/pda";
-import { airdrop, conn } from "./utils";
+import {
+  airdrop,
+  ALLOW_ALL_JSON_RULE,
+  conn,
+  createPolicy,
+  expectProgramError
+} from "./utils";
+
+const sleep = (ms: number) => new Promise((resolve) => setTimeout(resolve, ms));
 
 describe("policy", () => {
   const uuid = Keypair.generate().publicKey;
@@ -40,10 +91,11 @@ describe("policy", () => {
       const ix = createInitPolicyInstruction(
         {
           policy: findPolicyPk(uuid),
+          mintCounter: findMintCounterPk(findPolicyPk(uuid)),
           authority: alice.publicKey,
           uuid,
         },
-        { arg: { jsonRule, dynamicRoyalty: null } }
+        { arg: createInitPolicyArgStruct({ jsonRule }) }
       );
       await process_tx(conn, [ix], [alice]);
       const policy = await Policy.fromAccountAddress(conn, findPolicyPk(uuid));
@@ -67,10 +119,11 @@ describe("policy", () => {
       const ix = createInitPolicyInstruction(
         {
           policy: findPolicyPk(uuid),
+          mintCounter: findMintCounterPk(findPolicyPk(uuid)),
           authority: alice.publicKey,
           uuid,
         },
-        { arg: { jsonRule, dynamicRoyalty } }
+        { arg: createInitPolicyArgStruct({ jsonRule, dynamicRoyalty }) }
       );
       await process_tx(conn, [ix], [alice]);
       const policy = await Policy.fromAccountAddress(conn, findPolicyPk(uuid));
@@ -93,10 +146,11 @@ describe("policy", () => {
       const ix = createInitPolicyInstruction(
         {
           policy: findPolicyPk(uuid),
+          mintCounter: findMintCounterPk(findPolicyPk(uuid)),
           authority: alice.publicKey,
           uuid,
         },
-        { arg: { jsonRule, dynamicRoyalty: null } }
+        { arg: createInitPolicyArgStruct({ jsonRule }) }
       );
       await process_tx(conn, [ix], [alice]);
       const policy = await Policy.fromAccountAddress(conn, findPolicyPk(uuid));
@@ -121,10 +175,11 @@ describe("policy", () => {
       const ix = createInitPolicyInstruction(
         {
           policy: findPolicyPk(uuid),
+          mintCounter: findMintCounterPk(findPolicyPk(uuid)),
           authority: alice.publicKey,
           uuid,
         },
-        { arg: { jsonRule, dynamicRoyalty: null } }
+        { arg: createInitPolicyArgStruct({ jsonRule }) }
       );
       await process_tx(conn, [ix], [alice]);
       const policy = await Policy.fromAccountAddress(conn, findPolicyPk(uuid));
@@ -133,47 +188,815 @@ describe("policy", () => {
   });
 
   describe("Can update policy", () => {
-    it("alice set bob as the authority", async () => {
-      let ix: TransactionInstruction;
+    it("the authority arg must be the policy authority", async () => {
       const jsonRule = JSON.stringify({
         events: [],
         conditions: {
           and: [{ field: "action", operator: "string_not_equals", value: "" }],
         },
       });
+      let ix: TransactionInstruction;
       ix = createUpdatePolicyInstruction(
         { policy: findPolicyPk(uuid), authority: alice.publicKey },
-        { arg: { authority: bob.publicKey, jsonRule, dynamicRoyalty: null } }
+        {
+          arg: createUpdatePolicyArgStruct({
+            authority: bob.publicKey,
+            jsonRule,
+          }),
+        }
+      );
+      await expectProgramError(
+        process_tx(conn, [ix], [alice]),
+        new InvalidAuthorityError().code
+      );
+
+      ix = createUpdatePolicyInstruction(
+        { policy: findPolicyPk(uuid), authority: alice.publicKey },
+        {
+          arg: createUpdatePolicyArgStruct({
+            authority: alice.publicKey,
+            jsonRule,
+          }),
+        }
       );
       await process_tx(conn, [ix], [alice]);
-      {
-        const policy = await Policy.fromAccountAddress(
-          conn,
-          findPolicyPk(uuid)
-        );
-        assert.isTrue(policy.authority.equals(bob.publicKey));
-      }
+      const policy = await Policy.fromAccountAddress(conn, findPolicyPk(uuid));
+      assert.isTrue(policy.authority.equals(alice.publicKey));
+      assert.equal(policy.jsonRule, jsonRule);
 
       ix = createUpdatePolicyInstruction(
         { policy: findPolicyPk(uuid), authority: bob.publicKey },
-        { arg: { authority: alice.publicKey, jsonRule, dynamicRoyalty: null } }
+        {
+          arg: createUpdatePolicyArgStruct({
+            authority: alice.publicKey,
+            jsonRule,
+          }),
+        }
+      );
+      await expectProgramError(
+        process_tx(conn, [ix], [bob]),
+        new InvalidAuthorityError().code
+      );
+    });
+  });
+
+  describe("Can transfer the policy authority", () => {
+    it("alice nominates bob and bob accepts", async () => {
+      const policy = findPolicyPk(uuid);
+      const nominateIx = createNominatePolicyAuthorityInstruction(
+        { policy, authority: alice.publicKey },
+        { arg: { pendingAuthority: bob.publicKey } }
       );
-      await process_tx(conn, [ix], [bob]);
+      await process_tx(conn, [nominateIx], [alice]);
       {
-        const policy = await Policy.fromAccountAddress(
+        const policyAcc = await Policy.fromAccountAddress(conn, policy);
+        assert.isTrue(policyAcc.authority.equals(alice.publicKey));
+        assert.isTrue(policyAcc.pendingAuthority?.equals(bob.publicKey));
+      }
+
+      await expectProgramError(
+        process_tx(
           conn,
-          findPolicyPk(uuid)
-        );
-        assert.isTrue(policy.authority.equals(alice.publicKey));
+          [
+            createAcceptPolicyAuthorityInstruction({
+              policy,
+              pendingAuthority: eve.publicKey,
+            }),
+          ],
+          [eve]
+        ),
+        new InvalidAuthorityError().code
+      );
+
+      await process_tx(
+        conn,
+        [
+          createAcceptPolicyAuthorityInstruction({
+            policy,
+            pendingAuthority: bob.publicKey,
+          }),
+        ],
+        [bob]
+      );
+      {
+        const policyAcc = await Policy.fromAccountAddress(conn, policy);
+        assert.isTrue(policyAcc.authority.equals(bob.publicKey));
+        assert.isNull(policyAcc.pendingAuthority);
       }
 
+      // and back to alice
+      await process_tx(
+        conn,
+        [
+          createNominatePolicyAuthorityInstruction(
+            { policy, authority: bob.publicKey },
+            { arg: { pendingAuthority: alice.publicKey } }
+          ),
+          createAcceptPolicyAuthorityInstruction({
+            policy,
+            pendingAuthority: alice.publicKey,
+          }),
+        ],
+        [bob, alice]
+      );
+      const policyAcc = await Policy.fromAccountAddress(conn, policy);
+      assert.isTrue(policyAcc.authority.equals(alice.publicKey));
+    });
+  });
+
+  describe("Can set policy roles", () => {
+    it("the rule admin can only update the rules", async () => {
+      const policy = await createPolicy(conn, alice, {
+        jsonRule: ALLOW_ALL_JSON_RULE,
+      });
+      const ix = createSetPolicyRolesInstruction(
+        { policy, authority: alice.publicKey },
+        {
+          arg: {
+            ruleAdmin: bob.publicKey,
+            royaltyAdmin: null,
+            pauseGuardian: null,
+            unmanaged: false,
+          },
+        }
+      );
+      await process_tx(conn, [ix], [alice]);
+      const policyAcc = await Policy.fromAccountAddress(conn, policy);
+      assert.isTrue(policyAcc.ruleAdmin?.equals(bob.publicKey));
+
+      const jsonRule = JSON.stringify({
+        events: [],
+        conditions: {
+          field: "action",
+          operator: "string_not_equals",
+          value: "burn",
+        },
+      });
+      await process_tx(
+        conn,
+        [
+          createUpdatePolicyInstruction(
+            { policy, authority: bob.publicKey },
+            {
+              arg: createUpdatePolicyArgStruct({
+                authority: alice.publicKey,
+                jsonRule,
+              }),
+            }
+          ),
+        ],
+        [bob]
+      );
+      assert.equal(
+        (await Policy.fromAccountAddress(conn, policy)).jsonRule,
+        jsonRule
+      );
+
+      // the royalty fields are left to the authority
+      const dynamicRoyalty = createDynamicRoyaltyStruct({
+        startPrice: new anchor.BN(0),
+        endPrice: new anchor.BN(LAMPORTS_PER_SOL),
+        startMultiplierBp: 10000,
+        endMultiplierBp: 5000,
+      });
+      await expectProgramError(
+        process_tx(
+          conn,
+          [
+            createUpdatePolicyInstruction(
+              { policy, authority: bob.publicKey },
+              {
+                arg: createUpdatePolicyArgStruct({
+                  authority: alice.publicKey,
+                  jsonRule,
+                  dynamicRoyalty,
+                }),
+              }
+            ),
+          ],
+          [bob]
+        ),
+        new InvalidAuthorityError().code
+      );
+      await expectProgramError(
+        process_tx(
+          conn,
+          [
+            createUpdatePolicyInstruction(
+              { policy, authority: eve.publicKey },
+              {
+                arg: createUpdatePolicyArgStruct({
+                  authority: alice.publicKey,
+                  jsonRule,
+                }),
+              }
+            ),
+          ],
+          [eve]
+        ),
+        new InvalidAuthorityError().code
+      );
+    });
+  });
+
+  describe("Can timelock policy updates", () => {
+    it("propose, apply and cancel", async () => {
+      const updateDelay = new anchor.BN(2);
+      const policy = await createPolicy(conn, alice, {
+        jsonRule: ALLOW_ALL_JSON_RULE,
+        updateDelay,
+      });
+      const pendingPolicyUpdate = findPendingPolicyUpdatePk(policy);
+      const jsonRule = JSON.stringify({
+        events: [],
+        conditions: {
+          field: "action",
+          operator: "string_not_equals",
+          value: "burn",
+        },
+      });
+      const arg = createUpdatePolicyArgStruct({
+        authority: alice.publicKey,
+        jsonRule,
+        updateDelay,
+      });
+
+      await expectProgramError(
+        process_tx(
+          conn,
+          [
+            createUpdatePolicyInstruction(
+              { policy, authority: alice.publicKey },
+              { arg }
+            ),
+          ],
+          [alice]
+        ),
+        new PolicyUpdateTimelockedError().code
+      );
+      // the rule chunks would change the rules without a proposal
+      await expectProgramError(
+        process_tx(
+          conn,
+          [
+            createInitRuleChunkInstruction(
+              {
+                policy,
+                ruleChunk: findRuleChunkPk(policy, 0),
+                authority: alice.publicKey,
+              },
+              { arg: { index: 0 } }
+            ),
+          ],
+          [alice]
+        ),
+        new PolicyUpdateTimelockedError().code
+      );
+
+      await process_tx(
+        conn,
+        [
+          createProposePolicyUpdateInstruction(
+            { policy, pendingPolicyUpdate, authority: alice.publicKey },
+            { arg }
+          ),
+        ],
+        [alice]
+      );
+      const pending = await PendingPolicyUpdate.fromAccountAddress(
+        conn,
+        pendingPolicyUpdate
+      );
+      assert.equal(pending.update.jsonRule, jsonRule);
+
+      const applyIx = createApplyPolicyUpdateInstruction({
+        policy,
+        pendingPolicyUpdate,
+        authority: alice.publicKey,
+      });
+      await expectProgramError(
+        process_tx(conn, [applyIx], [alice]),
+        new PolicyUpdateNotEffectiveError().code
+      );
+
+      await sleep(3000);
+      await process_tx(conn, [applyIx], [alice]);
+      assert.equal(
+        (await Policy.fromAccountAddress(conn, policy)).jsonRule,
+        jsonRule
+      );
+      assert.isNull(await conn.getAccountInfo(pendingPolicyUpdate));
+
+      await process_tx(
+        conn,
+        [
+          createProposePolicyUpdateInstruction(
+            { policy, pendingPolicyUpdate, authority: alice.publicKey },
+            {
+              arg: createUpdatePolicyArgStruct({
+                authority: alice.publicKey,
+                jsonRule: ALLOW_ALL_JSON_RULE,
+                updateDelay,
+              }),
+            }
+          ),
+          createCancelPolicyUpdateInstruction({
+            policy,
+            pendingPolicyUpdate,
+            authority: alice.publicKey,
+          }),
+        ],
+        [alice]
+      );
+      assert.isNull(await conn.getAccountInfo(pendingPolicyUpdate));
+      assert.equal(
+        (await Policy.fromAccountAddress(conn, policy)).jsonRule,
+        jsonRule
+      );
+    });
+  });
+
+  describe("Can pause a policy", () => {
+    it("the pause guardian pauses and resumes the policy", async () => {
+      const policy = await createPolicy(conn, alice, {
+        jsonRule: ALLOW_ALL_JSON_RULE,
+      });
+      await process_tx(
+        conn,
+        [
+          createSetPolicyRolesInstruction(
+            { policy, authority: alice.publicKey },
+            {
+              arg: {
+                ruleAdmin: null,
+                royaltyAdmin: null,
+                pauseGuardian: bob.publicKey,
+                unmanaged: false,
+              },
+            }
+          ),
+        ],
+        [alice]
+      );
+      const pause = (paused: boolean, authority: Keypair) =>
+        process_tx(
+          conn,
+          [
+            createSetPolicyPausedInstruction(
+              { policy, authority: authority.publicKey },
+              { arg: { paused } }
+            ),
+          ],
+          [authority]
+        );
+
+      await expectProgramError(
+        pause(true, eve),
+        new InvalidAuthorityError().code
+      );
+      await pause(true, bob);
+      assert.isTrue((await Policy.fromAccountAddress(conn, policy)).paused);
+
+      // a paused policy can't be frozen, it couldn't be resumed anymore
+      await expectProgramError(
+        process_tx(
+          conn,
+          [
+            createFreezePolicyInstruction(
+              { policy, authority: alice.publicKey },
+              { arg: { freezeAuthority: false } }
+            ),
+          ],
+          [alice]
+        ),
+        new PolicyPausedError().code
+      );
+
+      await pause(false, bob);
+      assert.isFalse((await Policy.fromAccountAddress(conn, policy)).paused);
+    });
+  });
+
+  describe("Can freeze a policy", () => {
+    it("the rules and the roles can't change anymore", async () => {
+      const policy = await createPolicy(conn, alice, {
+        jsonRule: ALLOW_ALL_JSON_RULE,
+      });
+      await process_tx(
+        conn,
+        [
+          createFreezePolicyInstruction(
+            { policy, authority: alice.publicKey },
+            { arg: { freezeAuthority: true } }
+          ),
+        ],
+        [alice]
+      );
+      const policyAcc = await Policy.fromAccountAddress(conn, policy);
+      assert.isTrue(policyAcc.frozen);
+      assert.isTrue(policyAcc.authorityFrozen);
+
+      await expectProgramError(
+        process_tx(
+          conn,
+          [
+            createUpdatePolicyInstruction(
+              { policy, authority: alice.publicKey },
+              {
+                arg: createUpdatePolicyArgStruct({
+                  authority: alice.publicKey,
+                  jsonRule: ALLOW_ALL_JSON_RULE,
+                }),
+              }
+            ),
+          ],
+          [alice]
+        ),
+        new PolicyFrozenError().code
+      );
+      await expectProgramError(
+        process_tx(
+          conn,
+          [
+            createSetPolicyRolesInstruction(
+              { policy, authority: alice.publicKey },
+              {
+                arg: {
+                  ruleAdmin: bob.publicKey,
+                  royaltyAdmin: null,
+                  pauseGuardian: null,
+                  unmanaged: false,
+                },
+              }
+            ),
+          ],
+          [alice]
+        ),
+        new PolicyFrozenError().code
+      );
+    });
+
+    it("the lists that the rules reference must be frozen first", async () => {
+      const listUuid = Keypair.generate().publicKey;
+      const list = findListPk(listUuid);
+      await process_tx(
+        conn,
+        [
+          createInitListInstruction({
+            list,
+            uuid: listUuid,
+            authority: alice.publicKey,
+          }),
+        ],
+        [alice]
+      );
+      const policy = await createPolicy(conn, alice, {
+        jsonRule: JSON.stringify({
+          events: [],
+          conditions: {
+            not: { field: "to", operator: "in_list", value: list.toBase58() },
+          },
+        }),
+      });
+      const freezeIx = createFreezePolicyInstruction(
+        { policy, authority: alice.publicKey },
+        { arg: { freezeAuthority: false } }
+      );
+      freezeIx.keys.push({ pubkey: list, isWritable: false, isSigner: false });
+
+      // a frozen policy would find the list unknown while it can still change
+      await expectProgramError(
+        process_tx(conn, [freezeIx], [alice]),
+        new InvalidListAccountError().code
+      );
+      await process_tx(
+        conn,
+        [createFreezeListInstruction({ list, authority: alice.publicKey })],
+        [alice]
+      );
+      await process_tx(conn, [freezeIx], [alice]);
+      assert.isTrue((await Policy.fromAccountAddress(conn, policy)).frozen);
+    });
+  });
+
+  describe("Can set the program config", () => {
+    it("only the upgrade authority of the program", async () => {
+      const [programData] = PublicKey.findProgramAddressSync(
+        [PROGRAM_ID.toBuffer()],
+        BPF_LOADER_UPGRADEABLE_PROGRAM_ID
+      );
+      const ix = createSetProgramConfigInstruction(
+        {
+          programConfig: findProgramConfigPk(),
+          program: PROGRAM_ID,
+          programData,
+          upgradeAuthority: eve.publicKey,
+        },
+        { arg: { managedAuthorities: [eve.publicKey] } }
+      );
       try {
-        await process_tx(conn, [ix], [bob]);
+        await process_tx(conn, [ix], [eve]);
         assert.fail("should have failed");
       } catch (e: any) {
         assert.include(e.message, "Transaction simulation failed");
       }
+      assert.isNull(await conn.getAccountInfo(findProgramConfigPk()));
     });
   });
 
+  describe("Can write rule chunks", () => {
+    it("happy path", async () => {
+      const policy = await createPolicy(conn, alice, {
+        jsonRule: ALLOW_ALL_JSON_RULE,
+      });
+      const ruleChunk = findRuleChunkPk(policy, 0);
+      const data = Buffer.from(
+        JSON.stringify({
+          events: [],
+          conditions: {
+            field: "to",
+            operator: "string_does_not_contain_any",
+            value: Array(8)
+              .fill(0)
+              .map(() => Keypair.generate().publicKey.toBase58()),
+          },
+        })
+      );
+      const half = Math.floor(data.length / 2);
+      await process_tx(
+        conn,
+        [
+          createInitRuleChunkInstruction(
+            { policy, ruleChunk, authority: alice.publicKey },
+            { arg: { index: 0 } }
+          ),
+          createWriteRuleChunkInstruction(
+            { policy, ruleChunk, authority: alice.publicKey },
+            { arg: { index: 0, offset: 0, data: data.subarray(0, half) } }
+          ),
+        ],
+        [alice]
+      );
+      await expectProgramError(
+        process_tx(
+          conn,
+          [
+            createWriteRuleChunkInstruction(
+              { policy, ruleChunk, authority: eve.publicKey },
+              { arg: { index: 0, offset: half, data: data.subarray(half) } }
+            ),
+          ],
+          [eve]
+        ),
+        new InvalidAuthorityError().code
+      );
+
+      const updateIx = createUpdatePolicyInstruction(
+        { policy, authority: alice.publicKey },
+        {
+          arg: createUpdatePolicyArgStruct({
+            authority: alice.publicKey,
+            jsonRule: ALLOW_ALL_JSON_RULE,
+            ruleChunkCount: 1,
+          }),
+        }
+      );
+      updateIx.keys.push({
+        pubkey: ruleChunk,
+        isWritable: false,
+        isSigner: false,
+      });
+      // the first half isn't a complete json rule yet
+      await expectProgramError(
+        process_tx(conn, [updateIx], [alice]),
+        new InvalidRuleChunkError().code
+      );
+      await process_tx(
+        conn,
+        [
+          createWriteRuleChunkInstruction(
+            { policy, ruleChunk, authority: alice.publicKey },
+            { arg: { index: 0, offset: half, data: data.subarray(half) } }
+          ),
+          updateIx,
+        ],
+        [alice]
+      );
+      const ruleChunkAcc = await RuleChunk.fromAccountAddress(conn, ruleChunk);
+      assert.equal(
+        Buffer.from(ruleChunkAcc.jsonRule).toString(),
+        data.toString()
+      );
+      const policyAcc = await Policy.fromAccountAddress(conn, policy);
+      assert.equal(policyAcc.ruleChunkCount, 1);
+      // the mint counter and the rule chunk
+      assert.equal(policyAcc.accountCount, 2);
+
+      // the referenced rule chunk can't be rewritten
+      await expectProgramError(
+        process_tx(
+          conn,
+          [
+            createWriteRuleChunkInstruction(
+              { policy, ruleChunk, authority: alice.publicKey },
+              { arg: { index: 0, offset: 0, data: Buffer.from("{}") } }
+            ),
+          ],
+          [alice]
+        ),
+        new InvalidRuleChunkError().code
+      );
+    });
+  });
+
+  describe("Can init a rule counter", () => {
+    it("happy path", async () => {
+      const policy = await createPolicy(conn, alice, {
+        jsonRule: ALLOW_ALL_JSON_RULE,
+      });
+      const ruleCounter = findRuleCounterPk(policy, 3);
+      await process_tx(
+        conn,
+        [
+          createInitRuleCounterInstruction(
+            { policy, ruleCounter, authority: alice.publicKey },
+            { arg: { index: 3 } }
+          ),
+        ],
+        [alice]
+      );
+      const ruleCounterAcc = await RuleCounter.fromAccountAddress(
+        conn,
+        ruleCounter
+      );
+      assert.isTrue(ruleCounterAcc.policy.equals(policy));
+      assert.equal(ruleCounterAcc.index, 3);
+      assert.equal(ruleCounterAcc.count.toString(), "0");
+    });
+  });
+
+  describe("Can set a royalty exemption", () => {
+    it("happy path", async () => {
+      const policy = await createPolicy(conn, alice, {
+        jsonRule: ALLOW_ALL_JSON_RULE,
+      });
+      const royaltyExemption = findRoyaltyExemptionPk(policy);
+      const setExemption = (wallets: PublicKey[], programIds: PublicKey[]) =>
+        createSetRoyaltyExemptionInstruction(
+          { policy, royaltyExemption, authority: alice.publicKey },
+          { arg: { wallets, programIds } }
+        );
+
+      await process_tx(conn, [setExemption([bob.publicKey], [])], [alice]);
+      let exemption = await RoyaltyExemption.fromAccountAddress(
+        conn,
+        royaltyExemption
+      );
+      assert.isTrue(exemption.policy.equals(policy));
+      assert.equal(exemption.wallets.length, 1);
+      assert.isTrue(exemption.wallets[0].equals(bob.publicKey));
+
+      await process_tx(conn, [setExemption([], [PROGRAM_ID])], [alice]);
+      exemption = await RoyaltyExemption.fromAccountAddress(
+        conn,
+        royaltyExemption
+      );
+      assert.equal(exemption.wallets.length, 0);
+      assert.isTrue(exemption.programIds[0].equals(PROGRAM_ID));
+      // the exemption is only counted once
+      assert.equal(
+        (await Policy.fromAccountAddress(conn, policy)).accountCount,
+        2
+      );
+    });
+  });
+
+  describe("Can manage a list account", () => {
+    it("extend, shrink and freeze", async () => {
+      const listUuid = Keypair.generate().publicKey;
+      const list = findListPk(listUuid);
+      const keys = Array(3)
+        .fill(0)
+        .map(() => Keypair.generate().publicKey);
+      await process_tx(
+        conn,
+        [
+          createInitListInstruction({
+            list,
+            uuid: listUuid,
+            authority: alice.publicKey,
+          }),
+          createExtendListInstruction(
+            { list, authority: alice.publicKey },
+            { arg: { keys } }
+          ),
+          createShrinkListInstruction(
+            { list, authority: alice.publicKey },
+            { arg: { keys: [keys[1]] } }
+          ),
+        ],
+        [alice]
+      );
+      let listAcc = await ListAccount.fromAccountAddress(conn, list);
+      assert.sameMembers(
+        listAcc.keys.map((x) => x.toBase58()),
+        [keys[0], keys[2]].map((x) => x.toBase58())
+      );
+
+      await expectProgramError(
+        process_tx(
+          conn,
+          [
+            createExtendListInstruction(
+              { list, authority: eve.publicKey },
+              { arg: { keys: [eve.publicKey] } }
+            ),
+          ],
+          [eve]
+        ),
+        new InvalidAuthorityError().code
+      );
+
+      await process_tx(
+        conn,
+        [createFreezeListInstruction({ list, authority: alice.publicKey })],
+        [alice]
+      );
+      listAcc = await ListAccount.fromAccountAddress(conn, list);
+      assert.isTrue(listAcc.authority.equals(PublicKey.default));
+      await expectProgramError(
+        process_tx(
+          conn,
+          [
+            createExtendListInstruction(
+              { list, authority: alice.publicKey },
+              { arg: { keys: [eve.publicKey] } }
+            ),
+          ],
+          [alice]
+        ),
+        new InvalidAuthorityError().code
+      );
+    });
+  });
+
+  describe("Can close a policy", () => {
+    it("only with every account of the policy", async () => {
+      const policy = await createPolicy(conn, alice, {
+        jsonRule: ALLOW_ALL_JSON_RULE,
+      });
+      const ruleChunk = findRuleChunkPk(policy, 0);
+      await process_tx(
+        conn,
+        [
+          createInitRuleChunkInstruction(
+            { policy, ruleChunk, authority: alice.publicKey },
+            { arg: { index: 0 } }
+          ),
+        ],
+        [alice]
+      );
+      const closeIx = (accounts: PublicKey[]) => {
+        const ix = createClosePolicyInstruction({
+          policy,
+          authority: alice.publicKey,
+        });
+        ix.keys.push(
+          ...accounts.map((pubkey) => ({
+            pubkey,
+            isWritable: true,
+            isSigner: false,
+          }))
+        );
+        return ix;
+      };
+
+      await expectProgramError(
+        process_tx(conn, [closeIx([findMintCounterPk(policy)])], [alice]),
+        new PolicyInUseError().code
+      );
+      await expectProgramError(
+        process_tx(
+          conn,
+          [
+            createClosePolicyInstruction({
+              policy,
+              authority: eve.publicKey,
+            }),
+          ],
+          [eve]
+        ),
+        new InvalidAuthorityError().code
+      );
+
+      await process_tx(
+        conn,
+        [closeIx([findMintCounterPk(policy), ruleChunk])],
+        [alice]
+      );
+      assert.isNull(await conn.getAccountInfo(policy));
+      assert.isNull(await conn.getAccountInfo(ruleChunk));
+      assert.isNull(await conn.getAccountInfo(findMintCounterPk(policy)));
+    });
+  });
 });
@@ -7,32 +7,60 @@ import {
   getAssociatedTokenAddress,
   getMint
 } from "@solana/spl-token";
-import { Keypair, SYSVAR_INSTRUCTIONS_PUBKEY } from "@solana/web3.js";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+  SYSVAR_INSTRUCTIONS_PUBKEY
+} from "@solana/web3.js";
 import { assert } from "chai";
 import {
+  AccountNotFoundError,
   CMT_PROGRAM,
   computeBudgetIx,
   createApproveInstruction,
   createBurnInstruction,
   createCloseInstruction,
+  createClosePolicyInstruction,
+  createGetRoyaltyQuoteInstruction,
   createInitAccountInstruction,
+  createInitRuleChunkInstruction,
   createLockInstruction,
   createMigrateToMplInstruction,
   createMintToInstruction,
   createRevokeInstruction,
+  createSimulatePolicyInstruction,
   createTransferInstruction,
+  createTransferWithPaymentInstruction,
   createUnlockInstruction,
+  createUpdatePolicyArgStruct,
+  createUpdatePolicyInstruction,
+  createWriteRuleChunkInstruction,
   findFreezeAuthorityPk,
+  findMintCounterPk,
   findMintStatePk,
+  findRoyaltyReceiptPk,
+  findRuleChunkPk,
+  MintCounter,
+  mintCounterAccountMeta,
   MintState,
-  process_tx
+  PolicyInUseError,
+  policyTraceBeet,
+  process_tx,
+  RoyaltyReceipt,
+  royaltyQuoteBeet
 } from "../sdk/src";
 import {
   airdrop,
+  ALLOW_ALL_JSON_RULE,
   conn,
   createPolicyFixture,
+  createPolicy,
   createTestMintAndWrap,
-  DEVNET_POLICY_ALL
+  DEVNET_POLICY_ALL,
+  expectProgramError,
+  simulateReturnData
 } from "./utils";
 
 describe("policy", () => {
@@ -184,6 +212,55 @@ describe("policy", () => {
       }
 
     });
+
+    it("happy path with a mint counter then close the policy", async () => {
+      const policy = await createPolicy(conn, alice, {
+        jsonRule: ALLOW_ALL_JSON_RULE,
+      });
+      const [tokenMint, tokenAta] = await createTestMintAndWrap(
+        conn,
+        new anchor.Wallet(alice),
+        policy
+      );
+      let mintCounter = await MintCounter.fromAccountAddress(
+        conn,
+        findMintCounterPk(policy)
+      );
+      assert.equal(mintCounter.count.toString(), "1");
+
+      const closePolicyIx = createClosePolicyInstruction({
+        policy,
+        authority: alice.publicKey,
+      });
+      closePolicyIx.keys.push(mintCounterAccountMeta(policy));
+      await expectProgramError(
+        process_tx(conn, [closePolicyIx], [alice]),
+        new PolicyInUseError().code
+      );
+
+      const ix = createBurnInstruction({
+        policy,
+        freezeAuthority: findFreezeAuthorityPk(policy),
+        mint: tokenMint,
+        metadata: findMetadataPda(tokenMint),
+        mintState: findMintStatePk(tokenMint),
+        from: alice.publicKey,
+        fromAccount: tokenAta,
+        cmtProgram: CMT_PROGRAM,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+      });
+      ix.keys.push(mintCounterAccountMeta(policy));
+      await process_tx(conn, [computeBudgetIx, ix], [alice]);
+      mintCounter = await MintCounter.fromAccountAddress(
+        conn,
+        findMintCounterPk(policy)
+      );
+      assert.equal(mintCounter.count.toString(), "0");
+
+      await process_tx(conn, [closePolicyIx], [alice]);
+      assert.isNull(await conn.getAccountInfo(policy));
+      assert.isNull(await conn.getAccountInfo(findMintCounterPk(policy)));
+    });
   });
 
   describe("Can lock and unlock without approving a delegate", () => {
@@ -545,6 +622,385 @@ describe("policy", () => {
     });
   });
 
+  describe("Can transfer a token with payment", () => {
+    it("happy path with a royalty split", async () => {
+      const carol = Keypair.generate();
+      const policy = await createPolicy(conn, alice, {
+        jsonRule: ALLOW_ALL_JSON_RULE,
+        royaltySplit: [{ address: carol.publicKey, share: 100 }],
+      });
+      const [tokenMint, aliceAta] = await createTestMintAndWrap(
+        conn,
+        new anchor.Wallet(alice),
+        policy
+      );
+      const bobAta = await getAssociatedTokenAddress(tokenMint, bob.publicKey);
+      const price = new anchor.BN(LAMPORTS_PER_SOL);
+
+      // the quote of the seller_fee_basis_points of the metadata
+      const quoteIx = createGetRoyaltyQuoteInstruction(
+        {
+          policy,
+          metadata: findMetadataPda(tokenMint),
+          mintState: findMintStatePk(tokenMint),
+        },
+        {
+          arg: {
+            price,
+            from: alice.publicKey,
+            to: bob.publicKey,
+            callingProgram: null,
+          },
+        }
+      );
+      const [quote] = royaltyQuoteBeet.deserialize(
+        await simulateReturnData(conn, [quoteIx], alice.publicKey)
+      );
+      assert.equal(quote.royaltyBp, 500);
+      assert.equal(quote.royaltyAmount.toString(), `${LAMPORTS_PER_SOL / 20}`);
+      assert.equal(quote.recipients.length, 1);
+      assert.isTrue(quote.recipients[0].address.equals(carol.publicKey));
+
+      const initAccIx = createInitAccountInstruction({
+        policy,
+        freezeAuthority: findFreezeAuthorityPk(policy),
+        mint: tokenMint,
+        metadata: findMetadataPda(tokenMint),
+        mintState: findMintStatePk(tokenMint),
+        from: bob.publicKey,
+        fromAccount: bobAta,
+        cmtProgram: CMT_PROGRAM,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        payer: alice.publicKey,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      });
+      const royaltyReceipt = findRoyaltyReceiptPk(tokenMint, new anchor.BN(0));
+      const transferIx = createTransferWithPaymentInstruction(
+        {
+          policy,
+          freezeAuthority: findFreezeAuthorityPk(policy),
+          mint: tokenMint,
+          metadata: findMetadataPda(tokenMint),
+          mintState: findMintStatePk(tokenMint),
+          royaltyReceipt,
+          from: alice.publicKey,
+          fromAccount: aliceAta,
+          to: bob.publicKey,
+          toAccount: bobAta,
+          payer: bob.publicKey,
+          cmtProgram: CMT_PROGRAM,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        },
+        { arg: { price, merkleProofs: [] } }
+      );
+      transferIx.keys.push({
+        pubkey: carol.publicKey,
+        isWritable: true,
+        isSigner: false,
+      });
+      await process_tx(
+        conn,
+        [computeBudgetIx, initAccIx, transferIx],
+        [alice, bob]
+      );
+
+      const bobAtaAcc = await getAccount(conn, bobAta);
+      assert.equal(bobAtaAcc.amount.toString(), "1");
+      assert.equal(
+        await conn.getBalance(carol.publicKey),
+        LAMPORTS_PER_SOL / 20
+      );
+      const receipt = await RoyaltyReceipt.fromAccountAddress(
+        conn,
+        royaltyReceipt
+      );
+      assert.isTrue(receipt.payer.equals(bob.publicKey));
+      assert.equal(receipt.sequence.toString(), "0");
+      assert.equal(receipt.royaltyBp, 500);
+      assert.equal(
+        receipt.royaltyAmount.toString(),
+        `${LAMPORTS_PER_SOL / 20}`
+      );
+      const mintState = await MintState.fromAccountAddress(
+        conn,
+        findMintStatePk(tokenMint)
+      );
+      assert.equal(mintState.saleCount.toString(), "1");
+    });
+
+    it("happy path with a rule chunk of the policy", async () => {
+      const carol = Keypair.generate();
+      const policy = await createPolicy(conn, alice, {
+        jsonRule: ALLOW_ALL_JSON_RULE,
+        royaltySplit: [{ address: carol.publicKey, share: 100 }],
+      });
+      const [tokenMint, aliceAta] = await createTestMintAndWrap(
+        conn,
+        new anchor.Wallet(alice),
+        policy
+      );
+      const bobAta = await getAssociatedTokenAddress(tokenMint, bob.publicKey);
+      await process_tx(
+        conn,
+        [
+          computeBudgetIx,
+          createInitAccountInstruction({
+            policy,
+            freezeAuthority: findFreezeAuthorityPk(policy),
+            mint: tokenMint,
+            metadata: findMetadataPda(tokenMint),
+            mintState: findMintStatePk(tokenMint),
+            from: bob.publicKey,
+            fromAccount: bobAta,
+            cmtProgram: CMT_PROGRAM,
+            instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+            payer: alice.publicKey,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          }),
+        ],
+        [alice]
+      );
+
+      // the transfers now need the rule chunk in the remaining accounts
+      const ruleChunk = findRuleChunkPk(policy, 0);
+      const data = Buffer.from(
+        JSON.stringify({
+          events: [],
+          conditions: {
+            field: "to",
+            operator: "string_not_equals",
+            value: SystemProgram.programId.toBase58(),
+          },
+        })
+      );
+      const updateIx = createUpdatePolicyInstruction(
+        { policy, authority: alice.publicKey },
+        {
+          arg: createUpdatePolicyArgStruct({
+            authority: alice.publicKey,
+            jsonRule: ALLOW_ALL_JSON_RULE,
+            royaltySplit: [{ address: carol.publicKey, share: 100 }],
+            ruleChunkCount: 1,
+          }),
+        }
+      );
+      updateIx.keys.push({
+        pubkey: ruleChunk,
+        isWritable: false,
+        isSigner: false,
+      });
+      await process_tx(
+        conn,
+        [
+          createInitRuleChunkInstruction(
+            { policy, ruleChunk, authority: alice.publicKey },
+            { arg: { index: 0 } }
+          ),
+          createWriteRuleChunkInstruction(
+            { policy, ruleChunk, authority: alice.publicKey },
+            { arg: { index: 0, offset: 0, data } }
+          ),
+          updateIx,
+        ],
+        [alice]
+      );
+
+      const price = new anchor.BN(LAMPORTS_PER_SOL);
+      const transferIx = createTransferWithPaymentInstruction(
+        {
+          policy,
+          freezeAuthority: findFreezeAuthorityPk(policy),
+          mint: tokenMint,
+          metadata: findMetadataPda(tokenMint),
+          mintState: findMintStatePk(tokenMint),
+          royaltyReceipt: findRoyaltyReceiptPk(tokenMint, new anchor.BN(0)),
+          from: alice.publicKey,
+          fromAccount: aliceAta,
+          to: bob.publicKey,
+          toAccount: bobAta,
+          payer: bob.publicKey,
+          cmtProgram: CMT_PROGRAM,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        },
+        { arg: { price, merkleProofs: [] } }
+      );
+      const carolMeta = {
+        pubkey: carol.publicKey,
+        isWritable: true,
+        isSigner: false,
+      };
+      transferIx.keys.push(carolMeta);
+      await expectProgramError(
+        process_tx(conn, [computeBudgetIx, transferIx], [alice, bob]),
+        new AccountNotFoundError().code
+      );
+
+      transferIx.keys.push({
+        pubkey: ruleChunk,
+        isWritable: false,
+        isSigner: false,
+      });
+      await process_tx(conn, [computeBudgetIx, transferIx], [alice, bob]);
+      const bobAtaAcc = await getAccount(conn, bobAta);
+      assert.equal(bobAtaAcc.amount.toString(), "1");
+      assert.equal(
+        await conn.getBalance(carol.publicKey),
+        LAMPORTS_PER_SOL / 20
+      );
+    });
+
+    it("without the royalty recipients should fail", async () => {
+      const policy = await createPolicy(conn, alice, {
+        jsonRule: ALLOW_ALL_JSON_RULE,
+        royaltySplit: [{ address: eve.publicKey, share: 100 }],
+      });
+      const [tokenMint, aliceAta] = await createTestMintAndWrap(
+        conn,
+        new anchor.Wallet(alice),
+        policy
+      );
+      const bobAta = await getAssociatedTokenAddress(tokenMint, bob.publicKey);
+      const initAccIx = createInitAccountInstruction({
+        policy,
+        freezeAuthority: findFreezeAuthorityPk(policy),
+        mint: tokenMint,
+        metadata: findMetadataPda(tokenMint),
+        mintState: findMintStatePk(tokenMint),
+        from: bob.publicKey,
+        fromAccount: bobAta,
+        cmtProgram: CMT_PROGRAM,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        payer: alice.publicKey,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      });
+      const transferIx = createTransferWithPaymentInstruction(
+        {
+          policy,
+          freezeAuthority: findFreezeAuthorityPk(policy),
+          mint: tokenMint,
+          metadata: findMetadataPda(tokenMint),
+          mintState: findMintStatePk(tokenMint),
+          royaltyReceipt: findRoyaltyReceiptPk(tokenMint, new anchor.BN(0)),
+          from: alice.publicKey,
+          fromAccount: aliceAta,
+          to: bob.publicKey,
+          toAccount: bobAta,
+          payer: bob.publicKey,
+          cmtProgram: CMT_PROGRAM,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        },
+        { arg: { price: new anchor.BN(LAMPORTS_PER_SOL), merkleProofs: [] } }
+      );
+      await expectProgramError(
+        process_tx(
+          conn,
+          [computeBudgetIx, initAccIx, transferIx],
+          [alice, bob]
+        ),
+        new AccountNotFoundError().code
+      );
+    });
+  });
+
+  describe("Can simulate the policy", () => {
+    it("happy path with complex policy fixture", async () => {
+      const policy = await createPolicyFixture(conn, alice);
+      const [tokenMint] = await createTestMintAndWrap(
+        conn,
+        new anchor.Wallet(alice),
+        policy
+      );
+      const simulate = async (to: PublicKey) => {
+        const ix = createSimulatePolicyInstruction(
+          {
+            policy,
+            mint: tokenMint,
+            metadata: findMetadataPda(tokenMint),
+            mintState: findMintStatePk(tokenMint),
+            instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          },
+          {
+            arg: {
+              action: "transfer",
+              payer: alice.publicKey,
+              from: alice.publicKey,
+              to,
+              programIds: [],
+              merkleProofs: [],
+            },
+          }
+        );
+        const [trace] = policyTraceBeet.deserialize(
+          await simulateReturnData(conn, [ix], alice.publicKey)
+        );
+        return trace;
+      };
+
+      let trace = await simulate(SystemProgram.programId);
+      assert.isFalse(trace.passed);
+      assert.equal(trace.rules[0].rule, "json_rule");
+      trace = await simulate(bob.publicKey);
+      assert.isTrue(trace.passed);
+      assert.isFalse(trace.truncated);
+    });
+
+    it("returns the matched events and the policy error", async () => {
+      const policy = await createPolicy(conn, alice, {
+        jsonRule: JSON.stringify({
+          events: [{ type: "require_memo", params: {} }],
+          conditions: {
+            field: "to",
+            operator: "string_not_equals",
+            value: SystemProgram.programId.toBase58(),
+            error: { code: 7, message: "no transfers to the system program" },
+          },
+        }),
+      });
+      const [tokenMint] = await createTestMintAndWrap(
+        conn,
+        new anchor.Wallet(alice),
+        policy
+      );
+      const simulate = async (to: PublicKey) => {
+        const ix = createSimulatePolicyInstruction(
+          {
+            policy,
+            mint: tokenMint,
+            mintState: findMintStatePk(tokenMint),
+            instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          },
+          {
+            arg: {
+              action: "transfer",
+              payer: null,
+              from: alice.publicKey,
+              to,
+              programIds: [],
+              merkleProofs: [],
+            },
+          }
+        );
+        const [trace] = policyTraceBeet.deserialize(
+          await simulateReturnData(conn, [ix], alice.publicKey)
+        );
+        return trace;
+      };
+
+      // the same code and message as the PolicyDenied event
+      let trace = await simulate(SystemProgram.programId);
+      assert.isFalse(trace.passed);
+      assert.isEmpty(trace.events);
+      assert.equal(trace.error?.code, 7);
+      assert.equal(trace.error?.message, "no transfers to the system program");
+
+      // the rule matches, but the simulated transaction has no memo
+      trace = await simulate(bob.publicKey);
+      assert.isFalse(trace.passed);
+      assert.isNull(trace.error);
+      assert.deepEqual(trace.events.map((x) => x.__kind), ["RequireMemo"]);
+    });
+  });
+
   describe("Can migrate to mpl", () => {
     it("happy path", async () => {
       const [tokenMint, tokenAta] = await createTestMintAndWrap(
@@ -8,6 +8,7 @@ import {
   DataV2,
 } from "@metaplex-foundation/mpl-token-metadata";
 import * as anchor from "@project-serum/anchor";
+import { assert } from "chai";
 import {
   AccountLayout,
   ASSOCIATED_TOKEN_PROGRAM_ID,
@@ -27,18 +28,23 @@ import {
   SystemProgram,
   SYSVAR_INSTRUCTIONS_PUBKEY,
   Transaction,
+  TransactionInstruction,
 } from "@solana/web3.js";
 import {
   CMT_PROGRAM,
   createDynamicRoyaltyStruct,
   createInitAccountInstruction,
+  createInitPolicyArgStruct,
   createInitPolicyInstruction,
   createMintToInstruction as ocpCreateMintToInstruction,
   createWrapInstruction,
   findFreezeAuthorityPk,
+  findMintCounterPk,
   findMintStatePk,
   findPolicyPk,
+  InitPolicyArg,
   LARGER_COMPUTE_UNIT,
+  mintCounterAccountMeta,
   process_tx,
 } from "../sdk/src";
 
@@ -82,19 +88,21 @@ export async function createTestMintAndWrap(
   );
   tx.recentBlockhash = (await connection.getLatestBlockhash()).blockhash;
   tx.feePayer = wallet.publicKey;
+  const wrapIx = createWrapInstruction({
+    mint: mintKeypair.publicKey,
+    policy,
+    freezeAuthority: wallet.publicKey,
+    mintAuthority: wallet.publicKey,
+    mintState: findMintStatePk(mintKeypair.publicKey),
+    from: wallet.payer.publicKey,
+    instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+    cmtProgram: CMT_PROGRAM,
+    metadata: findMetadataPda(mintKeypair.publicKey),
+  });
+  wrapIx.keys.push(mintCounterAccountMeta(policy));
   tx.add(
     ComputeBudgetProgram.setComputeUnitLimit({ units: LARGER_COMPUTE_UNIT }),
-    createWrapInstruction({
-      mint: mintKeypair.publicKey,
-      policy,
-      freezeAuthority: wallet.publicKey,
-      mintAuthority: wallet.publicKey,
-      mintState: findMintStatePk(mintKeypair.publicKey),
-      from: wallet.payer.publicKey,
-      instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
-      cmtProgram: CMT_PROGRAM,
-      metadata: findMetadataPda(mintKeypair.publicKey),
-    }),
+    wrapIx,
     createInitAccountInstruction({
       policy,
       freezeAuthority: findFreezeAuthorityPk(policy),
@@ -205,8 +213,6 @@ export const getTokenAccountRent = async (conn: Connection) => {
 };
 
 export const createPolicyFixture = async (conn: Connection, payer: Keypair) => {
-  const uuid = Keypair.generate().publicKey;
-  const policy = findPolicyPk(uuid);
   const jsonRule = JSON.stringify({
     events: [],
     conditions: {
@@ -248,10 +254,64 @@ export const createPolicyFixture = async (conn: Connection, payer: Keypair) => {
     startPrice: new anchor.BN(0),
     endPrice: new anchor.BN(5 * LAMPORTS_PER_SOL),
   });
+  return createPolicy(conn, payer, {
+    jsonRule,
+    dynamicRoyalty: dr,
+  });
+};
+
+export const ALLOW_ALL_JSON_RULE = JSON.stringify({
+  events: [],
+  conditions: { field: "action", operator: "string_not_equals", value: "" },
+});
+
+// init_policy creates the mint counter of the policy as well, wrap needs it
+export const createPolicy = async (
+  conn: Connection,
+  payer: Keypair,
+  arg: Partial<InitPolicyArg>
+) => {
+  const uuid = Keypair.generate().publicKey;
+  const policy = findPolicyPk(uuid);
   const ix = createInitPolicyInstruction(
-    { policy, uuid, authority: payer.publicKey },
-    { arg: { jsonRule, dynamicRoyalty: dr } }
+    {
+      policy,
+      mintCounter: findMintCounterPk(policy),
+      uuid,
+      authority: payer.publicKey,
+    },
+    { arg: createInitPolicyArgStruct(arg) }
   );
   await process_tx(conn, [ix], [payer]);
   return policy;
 };
+
+// the return data of get_royalty_quote or simulate_policy
+export const simulateReturnData = async (
+  conn: Connection,
+  ixs: TransactionInstruction[],
+  feePayer: PublicKey
+): Promise<Buffer> => {
+  const tx = new Transaction().add(...ixs);
+  tx.feePayer = feePayer;
+  tx.recentBlockhash = (await conn.getLatestBlockhash()).blockhash;
+  const { value } = await conn.simulateTransaction(tx);
+  if (value.err || !value.returnData) {
+    throw new Error(`simulation failed: ${JSON.stringify(value.logs)}`);
+  }
+  return Buffer.from(value.returnData.data[0], "base64");
+};
+
+// the preflight of process_tx reports the custom program error code in hex
+export const expectProgramError = async (
+  promise: Promise<unknown>,
+  code: number
+) => {
+  try {
+    await promise;
+  } catch (e: any) {
+    assert.include(e.message, `custom program error: 0x${code.toString(16)}`);
+    return;
+  }
+  assert.fail("should have failed");
+};